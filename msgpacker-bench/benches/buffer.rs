@@ -0,0 +1,65 @@
+use bytes::BytesMut;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use msgpacker::prelude::*;
+use rand::{distributions::Standard, prelude::*};
+use std::iter;
+
+/// The encoding of a `bin` payload through an `Extend<u8>` iterator chain.
+fn pack_extend<T>(buf: &mut T, payload: &[u8]) -> usize
+where
+    T: Extend<u8>,
+{
+    buf.extend(iter::once(0xc6).chain((payload.len() as u32).to_be_bytes()));
+    buf.extend(payload.iter().copied());
+    5 + payload.len()
+}
+
+pub fn buffer(c: &mut Criterion) {
+    let lens = [16, 1024, 65536, 1 << 20];
+
+    let mut group = c.benchmark_group("buffer");
+
+    for len in lens {
+        let payload: Vec<u8> = StdRng::from_seed([0xfa; 32])
+            .sample_iter(&Standard)
+            .take(len)
+            .collect();
+
+        group.bench_with_input(format!("extend vec {len}"), &payload, |b, payload| {
+            b.iter_batched(
+                || Vec::with_capacity(len + 5),
+                |mut buf| pack_extend(black_box(&mut buf), black_box(payload)),
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(format!("packbuf vec {len}"), &payload, |b, payload| {
+            b.iter_batched(
+                || Vec::with_capacity(len + 5),
+                |mut buf| black_box(payload.as_slice()).pack(black_box(&mut buf)),
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(format!("packbuf bytesmut {len}"), &payload, |b, payload| {
+            b.iter_batched(
+                || BytesMut::with_capacity(len + 5),
+                |mut buf| black_box(payload.as_slice()).pack(black_box(&mut buf)),
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(format!("packbuf slice {len}"), &payload, |b, payload| {
+            b.iter_batched(
+                || vec![0u8; len + 5],
+                |mut buf| black_box(payload.as_slice()).pack(black_box(&mut buf.as_mut_slice())),
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, buffer);
+criterion_main!(benches);
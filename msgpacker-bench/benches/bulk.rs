@@ -0,0 +1,85 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use rand::{distributions::Standard, prelude::*};
+
+pub fn bulk(c: &mut Criterion) {
+    let lens = [16, 1024, 65536, 1 << 20];
+
+    let mut group = c.benchmark_group("bulk");
+
+    for len in lens {
+        let floats: Vec<f32> = StdRng::from_seed([0xfa; 32])
+            .sample_iter(&Standard)
+            .take(len)
+            .collect();
+        let ints: Vec<u64> = StdRng::from_seed([0xfb; 32])
+            .sample_iter(&Standard)
+            .take(len)
+            .collect();
+
+        group.bench_with_input(format!("pack array f32 {len}"), &floats, |b, floats| {
+            b.iter_batched(
+                || Vec::with_capacity(len * 5 + 5),
+                |mut buf| msgpacker::pack_array(black_box(&mut buf), black_box(floats)),
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(format!("pack slice f32 {len}"), &floats, |b, floats| {
+            b.iter_batched(
+                || Vec::with_capacity(len * 5 + 5),
+                |mut buf| msgpacker::pack_f32_slice(black_box(&mut buf), black_box(floats)),
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(format!("pack array u64 {len}"), &ints, |b, ints| {
+            b.iter_batched(
+                || Vec::with_capacity(len * 9 + 5),
+                |mut buf| msgpacker::pack_array(black_box(&mut buf), black_box(ints)),
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(format!("pack slice u64 {len}"), &ints, |b, ints| {
+            b.iter_batched(
+                || Vec::with_capacity(len * 9 + 5),
+                |mut buf| msgpacker::pack_u64_slice(black_box(&mut buf), black_box(ints)),
+                BatchSize::LargeInput,
+            );
+        });
+
+        let mut packed_floats = Vec::new();
+        msgpacker::pack_f32_slice(&mut packed_floats, &floats);
+        let mut packed_ints = Vec::new();
+        msgpacker::pack_u64_slice(&mut packed_ints, &ints);
+
+        group.bench_with_input(
+            format!("unpack array f32 {len}"),
+            &packed_floats,
+            |b, buf| {
+                b.iter(|| {
+                    msgpacker::unpack_array::<f32, Vec<f32>>(black_box(buf)).unwrap();
+                });
+            },
+        );
+
+        group.bench_with_input(format!("unpack vec f32 {len}"), &packed_floats, |b, buf| {
+            b.iter(|| msgpacker::unpack_f32_vec(black_box(buf)).unwrap());
+        });
+
+        group.bench_with_input(format!("unpack array u64 {len}"), &packed_ints, |b, buf| {
+            b.iter(|| {
+                msgpacker::unpack_array::<u64, Vec<u64>>(black_box(buf)).unwrap();
+            });
+        });
+
+        group.bench_with_input(format!("unpack vec u64 {len}"), &packed_ints, |b, buf| {
+            b.iter(|| msgpacker::unpack_u64_vec(black_box(buf)).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bulk);
+criterion_main!(benches);
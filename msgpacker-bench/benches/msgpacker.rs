@@ -22,7 +22,7 @@ pub fn pack(c: &mut Criterion) {
 
         let mut buf = Vec::new();
         let mut serializer = Serializer::new(&mut buf);
-        (&values[..count]).serialize(&mut serializer).unwrap();
+        values[..count].serialize(&mut serializer).unwrap();
         bufs_rmps.push(buf);
     }
 
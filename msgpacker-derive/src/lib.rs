@@ -8,32 +8,289 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::{
-    parse_macro_input, parse_quote, parse_str, Block, Data, DataEnum, DataStruct, DataUnion,
-    DeriveInput, Expr, ExprMatch, ExprTuple, Field, FieldPat, FieldValue, Fields, FieldsNamed,
-    FieldsUnnamed, GenericArgument, Ident, Member, Meta, Pat, PatIdent, PathArguments, Token, Type,
-    Variant,
+    parse_macro_input, parse_quote, parse_str, Attribute, Block, Data, DataEnum, DataStruct,
+    DataUnion, DeriveInput, Expr, ExprLit, ExprMatch, ExprTuple, Field, FieldPat, FieldValue,
+    Fields, FieldsNamed, FieldsUnnamed, File, Ident, Item, ItemImpl, Lit, Member, Meta, Pat,
+    PatIdent, Stmt, Token, Variant,
 };
 
 fn contains_attribute(field: &Field, name: &str) -> bool {
     let name = name.to_string();
     if let Some(attr) = field.attrs.first() {
         if let Meta::List(list) = &attr.meta {
-            if list.path.is_ident("msgpacker") {
-                if list
+            if list.path.is_ident("msgpacker")
+                && list
                     .tokens
                     .clone()
                     .into_iter()
                     .find(|a| a.to_string() == name)
                     .is_some()
-                {
-                    return true;
-                }
+            {
+                return true;
             }
         }
     }
     false
 }
 
+/// Returns the extension type of a container annotated with `#[msgpacker(ext = <type>)]`.
+fn ext_attribute(attrs: &[Attribute]) -> Option<Expr> {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("msgpacker"))
+        .filter_map(|a| {
+            a.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|m| match m {
+            Meta::NameValue(nv) if nv.path.is_ident("ext") => Some(nv.value),
+            _ => None,
+        })
+}
+
+/// Collection packed with its elements sorted, declared by `#[msgpacker(sorted = "set" | "map")]`.
+///
+/// A bare `#[msgpacker(sorted)]` is accepted next to `array` or `map`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sorted {
+    Unspecified,
+    Set,
+    Map,
+}
+
+/// Returns the sorted collection declared by the attributes of a field, if any.
+fn sorted_attribute(field: &Field) -> Option<Sorted> {
+    field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("msgpacker"))
+        .filter_map(|a| {
+            a.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|m| match m {
+            Meta::Path(p) if p.is_ident("sorted") => Some(Sorted::Unspecified),
+            Meta::NameValue(nv) if nv.path.is_ident("sorted") => match &nv.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) if s.value() == "set" => Some(Sorted::Set),
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) if s.value() == "map" => Some(Sorted::Map),
+                _ => panic!("`#[msgpacker(sorted)]` expects either `sorted = \"set\"` or `sorted = \"map\"`"),
+            },
+            _ => None,
+        })
+}
+
+/// Returns the statements packing, try packing, measuring and unpacking from a slice and from an
+/// iterator a field, read from `value` and unpacked into `var`.
+fn field_stmts(field: &Field, value: &Expr, var: &Expr) -> [Stmt; 5] {
+    let ty = &field.ty;
+    let sorted = sorted_attribute(field);
+    let is_sorted = sorted.is_some();
+    let is_unique = contains_attribute(field, "unique");
+    let is_array = contains_attribute(field, "array") || sorted == Some(Sorted::Set);
+    let is_map = contains_attribute(field, "map") || is_unique || sorted == Some(Sorted::Map);
+
+    if is_sorted && !is_array && !is_map {
+        panic!("`#[msgpacker(sorted)]` expects `sorted = \"set\"`, `sorted = \"map\"`, or an `array` or `map` directive");
+    }
+
+    if is_map {
+        let (pack, try_pack) = if is_sorted {
+            (quote! { pack_map_sorted }, quote! { try_pack_map_sorted })
+        } else {
+            (quote! { pack_map }, quote! { try_pack_map })
+        };
+        let (unpack, unpack_iter) = if is_unique {
+            (
+                quote! { unpack_map_unique },
+                quote! { unpack_map_unique_iter },
+            )
+        } else {
+            (quote! { unpack_map }, quote! { unpack_map_iter })
+        };
+
+        [
+            parse_quote! {
+                n += ::msgpacker::#pack(buf, &#value);
+            },
+            parse_quote! {
+                n += ::msgpacker::#try_pack(buf, &#value)?;
+            },
+            parse_quote! {
+                n += ::msgpacker::packed_map_len(&#value);
+            },
+            parse_quote! {
+                let #var = ::msgpacker::#unpack(buf).map(|(nv, t)| {
+                    n += nv;
+                    buf = &buf[nv..];
+                    t
+                })?;
+            },
+            parse_quote! {
+                let #var = ::msgpacker::#unpack_iter(bytes.by_ref()).map(|(nv, t)| {
+                    n += nv;
+                    t
+                })?;
+            },
+        ]
+    } else if is_array {
+        let (pack, try_pack) = if is_sorted {
+            (
+                quote! { pack_array_sorted },
+                quote! { try_pack_array_sorted },
+            )
+        } else {
+            (quote! { pack_array }, quote! { try_pack_array })
+        };
+
+        [
+            parse_quote! {
+                n += ::msgpacker::#pack(buf, &#value);
+            },
+            parse_quote! {
+                n += ::msgpacker::#try_pack(buf, &#value)?;
+            },
+            parse_quote! {
+                n += ::msgpacker::packed_array_len(&#value);
+            },
+            parse_quote! {
+                let #var = ::msgpacker::unpack_array(buf).map(|(nv, t)| {
+                    n += nv;
+                    buf = &buf[nv..];
+                    t
+                })?;
+            },
+            parse_quote! {
+                let #var = ::msgpacker::unpack_array_iter(bytes.by_ref()).map(|(nv, t)| {
+                    n += nv;
+                    t
+                })?;
+            },
+        ]
+    } else {
+        [
+            parse_quote! {
+                n += <#ty as ::msgpacker::Packable>::pack(&#value, buf);
+            },
+            parse_quote! {
+                n += <#ty as ::msgpacker::Packable>::try_pack(&#value, buf)?;
+            },
+            parse_quote! {
+                n += <#ty as ::msgpacker::Packable>::packed_len(&#value);
+            },
+            parse_quote! {
+                let #var = ::msgpacker::Unpackable::unpack(buf).map(|(nv, t)| {
+                    n += nv;
+                    buf = &buf[nv..];
+                    t
+                })?;
+            },
+            parse_quote! {
+                let #var = ::msgpacker::Unpackable::unpack_iter(bytes.by_ref()).map(|(nv, t)| {
+                    n += nv;
+                    t
+                })?;
+            },
+        ]
+    }
+}
+
+/// Implements the traits of an extension type, packing the fields as its payload.
+fn impl_ext(name: Ident, ty: Expr, payload: TokenStream) -> TokenStream {
+    let mut payload: File = syn::parse(payload).unwrap();
+    for item in payload.items.iter_mut() {
+        if let Item::Impl(ItemImpl {
+            trait_: Some((_, path, _)),
+            ..
+        }) = item
+        {
+            if path
+                .segments
+                .last()
+                .filter(|s| s.ident == "Packable")
+                .is_some()
+            {
+                *path = parse_quote!(::msgpacker::__private::PackPayload);
+            } else if path
+                .segments
+                .last()
+                .filter(|s| s.ident == "Unpackable")
+                .is_some()
+            {
+                *path = parse_quote!(::msgpacker::__private::UnpackPayload);
+            }
+        }
+    }
+
+    quote! {
+        #payload
+
+        impl ::msgpacker::MsgPackExt for #name {
+            const TYPE: i8 = #ty;
+
+            fn payload_len(&self) -> usize {
+                <Self as ::msgpacker::__private::PackPayload>::packed_len(self)
+            }
+
+            fn encode<T>(&self, buf: &mut T)
+            where
+                T: ::msgpacker::PackBuf,
+            {
+                <Self as ::msgpacker::__private::PackPayload>::pack(self, buf);
+            }
+
+            fn decode(payload: &[u8]) -> Result<Self, ::msgpacker::Error> {
+                let (n, x) = <Self as ::msgpacker::__private::UnpackPayload>::unpack(payload)?;
+                if n != payload.len() {
+                    return Err(::msgpacker::Error::InvalidExtension);
+                }
+                Ok(x)
+            }
+        }
+
+        impl ::msgpacker::Packable for #name {
+            fn pack<T>(&self, buf: &mut T) -> usize
+            where
+                T: ::msgpacker::PackBuf,
+            {
+                ::msgpacker::pack_ext(buf, self)
+            }
+
+            fn try_pack<T>(&self, buf: &mut T) -> Result<usize, ::msgpacker::PackError>
+            where
+                T: ::msgpacker::PackBuf,
+            {
+                ::msgpacker::try_pack_ext(buf, self)
+            }
+
+            fn packed_len(&self) -> usize {
+                ::msgpacker::packed_ext_len(self)
+            }
+        }
+
+        impl ::msgpacker::Unpackable for #name {
+            type Error = ::msgpacker::Error;
+
+            fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                ::msgpacker::unpack_ext(buf)
+            }
+
+            fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+            where
+                I: IntoIterator<Item = u8>,
+            {
+                ::msgpacker::unpack_ext_iter(bytes)
+            }
+        }
+    }
+    .into()
+}
+
 fn impl_fields_named(name: Ident, f: FieldsNamed) -> impl Into<TokenStream> {
     let mut values: Punctuated<FieldValue, Token![,]> = Punctuated::new();
     let block_packable: Block = parse_quote! {
@@ -41,6 +298,16 @@ fn impl_fields_named(name: Ident, f: FieldsNamed) -> impl Into<TokenStream> {
             let mut n = 0;
         }
     };
+    let block_packed_len: Block = parse_quote! {
+        {
+            let mut n = 0;
+        }
+    };
+    let block_try_packable: Block = parse_quote! {
+        {
+            let mut n = 0;
+        }
+    };
     let block_unpackable: Block = parse_quote! {
         {
             let mut n = 0;
@@ -55,107 +322,64 @@ fn impl_fields_named(name: Ident, f: FieldsNamed) -> impl Into<TokenStream> {
 
     let (
         mut block_packable,
+        mut block_try_packable,
+        mut block_packed_len,
         mut block_unpackable,
-        mut block_unpackable_iter
+        mut block_unpackable_iter,
     ) = f.named.into_pairs().map(|p| p.into_value()).fold(
-            (block_packable, block_unpackable, block_unpackable_iter),
-            |(mut block_packable, mut block_unpackable, mut block_unpackable_iter), field| {
-                let ident = field.ident.as_ref().cloned().unwrap();
-                let ty = field.ty.clone();
-
-                let mut is_vec = false;
-                let mut is_vec_u8 = false;
-
-                match &ty {
-                    Type::Path(p) if p.path.segments.last().filter(|p| p.ident.to_string() == "Vec").is_some() => {
-                        is_vec = true;
-                        match &p.path.segments.last().unwrap().arguments {
-                            PathArguments::AngleBracketed(a) if a.args.len() == 1 => {
-                                if let Some(GenericArgument::Type(Type::Path(p))) = a.args.first() {
-                                    if p.path.segments.last().filter(|p| p.ident.to_string() == "u8").is_some() {
-                                        is_vec_u8 = true;
-                                    }
-                                }
-                            }
-                            _ => (),
-                        }
-                    }
-
-                    _ => (),
-                }
-
-                if contains_attribute(&field, "map") {
-                    block_packable.stmts.push(parse_quote! {
-                        n += ::msgpacker::pack_map(buf, &self.#ident);
-                    });
-
-                    block_unpackable.stmts.push(parse_quote! {
-                        let #ident = ::msgpacker::unpack_map(buf).map(|(nv, t)| {
-                            n += nv;
-                            buf = &buf[nv..];
-                            t
-                        })?;
-                    });
-
-                    block_unpackable_iter.stmts.push(parse_quote! {
-                        let #ident = ::msgpacker::unpack_map_iter(bytes.by_ref()).map(|(nv, t)| {
-                            n += nv;
-                            t
-                        })?;
-                    });
-                } else if contains_attribute(&field, "array") || is_vec && !is_vec_u8 {
-                    block_packable.stmts.push(parse_quote! {
-                        n += ::msgpacker::pack_array(buf, &self.#ident);
-                    });
-
-                    block_unpackable.stmts.push(parse_quote! {
-                        let #ident = ::msgpacker::unpack_array(buf).map(|(nv, t)| {
-                            n += nv;
-                            buf = &buf[nv..];
-                            t
-                        })?;
-                    });
-
-                    block_unpackable_iter.stmts.push(parse_quote! {
-                        let #ident = ::msgpacker::unpack_array_iter(bytes.by_ref()).map(|(nv, t)| {
-                            n += nv;
-                            t
-                        })?;
-                    });
-                } else {
-                    block_packable.stmts.push(parse_quote! {
-                        n += <#ty as ::msgpacker::Packable>::pack(&self.#ident, buf);
-                    });
-
-                    block_unpackable.stmts.push(parse_quote! {
-                        let #ident = ::msgpacker::Unpackable::unpack(buf).map(|(nv, t)| {
-                            n += nv;
-                            buf = &buf[nv..];
-                            t
-                        })?;
-                    });
-
-                    block_unpackable_iter.stmts.push(parse_quote! {
-                        let #ident = ::msgpacker::Unpackable::unpack_iter(bytes.by_ref()).map(|(nv, t)| {
-                            n += nv;
-                            t
-                        })?;
-                    });
-                }
-
+        (
+            block_packable,
+            block_try_packable,
+            block_packed_len,
+            block_unpackable,
+            block_unpackable_iter,
+        ),
+        |(
+            mut block_packable,
+            mut block_try_packable,
+            mut block_packed_len,
+            mut block_unpackable,
+            mut block_unpackable_iter,
+        ),
+         field| {
+            let ident = field.ident.as_ref().cloned().unwrap();
+            let value: Expr = parse_quote! { self.#ident };
+            let var: Expr = parse_quote! { #ident };
+
+            let [packable, try_packable, packed_len, unpackable, unpackable_iter] =
+                field_stmts(&field, &value, &var);
+            block_packable.stmts.push(packable);
+            block_try_packable.stmts.push(try_packable);
+            block_packed_len.stmts.push(packed_len);
+            block_unpackable.stmts.push(unpackable);
+            block_unpackable_iter.stmts.push(unpackable_iter);
+
+            values.push(FieldValue {
+                attrs: vec![],
+                member: Member::Named(ident.clone()),
+                colon_token: Some(<Token![:]>::default()),
+                expr: parse_quote! { #ident },
+            });
+
+            (
+                block_packable,
+                block_try_packable,
+                block_packed_len,
+                block_unpackable,
+                block_unpackable_iter,
+            )
+        },
+    );
 
-                values.push(FieldValue {
-                    attrs: vec![],
-                    member: Member::Named(ident.clone()),
-                    colon_token: Some(<Token![:]>::default()),
-                    expr: parse_quote! { #ident },
-                });
+    block_packable.stmts.push(parse_quote! {
+        return n;
+    });
 
-                (block_packable, block_unpackable, block_unpackable_iter)
-            },
-        );
+    block_try_packable.stmts.push(parse_quote! {
+        return Ok(n);
+    });
 
-    block_packable.stmts.push(parse_quote! {
+    block_packed_len.stmts.push(parse_quote! {
         return n;
     });
 
@@ -181,8 +405,16 @@ fn impl_fields_named(name: Ident, f: FieldsNamed) -> impl Into<TokenStream> {
         impl ::msgpacker::Packable for #name {
             fn pack<T>(&self, buf: &mut T) -> usize
             where
-                T: Extend<u8>,
+                T: ::msgpacker::PackBuf,
                 #block_packable
+
+            fn try_pack<T>(&self, buf: &mut T) -> Result<usize, ::msgpacker::PackError>
+            where
+                T: ::msgpacker::PackBuf,
+                #block_try_packable
+
+            fn packed_len(&self) -> usize
+                #block_packed_len
         }
 
         impl ::msgpacker::Unpackable for #name {
@@ -206,6 +438,16 @@ fn impl_fields_unnamed(name: Ident, f: FieldsUnnamed) -> impl Into<TokenStream>
             let mut n = 0;
         }
     };
+    let block_packed_len: Block = parse_quote! {
+        {
+            let mut n = 0;
+        }
+    };
+    let block_try_packable: Block = parse_quote! {
+        {
+            let mut n = 0;
+        }
+    };
     let block_unpackable: Block = parse_quote! {
         {
             let mut n = 0;
@@ -218,46 +460,53 @@ fn impl_fields_unnamed(name: Ident, f: FieldsUnnamed) -> impl Into<TokenStream>
         }
     };
 
-    let (mut block_packable, mut block_unpackable, mut block_unpackable_iter) = f
+    let (
+        mut block_packable,
+        mut block_try_packable,
+        mut block_packed_len,
+        mut block_unpackable,
+        mut block_unpackable_iter,
+    ) = f
         .unnamed
         .into_pairs()
         .map(|p| p.into_value())
         .enumerate()
         .fold(
-            (block_packable, block_unpackable, block_unpackable_iter),
-            |(mut block_packable, mut block_unpackable, mut block_unpackable_iter), (i, field)| {
-                let ty = field.ty.clone();
+            (
+                block_packable,
+                block_try_packable,
+                block_packed_len,
+                block_unpackable,
+                block_unpackable_iter,
+            ),
+            |(
+                mut block_packable,
+                mut block_try_packable,
+                mut block_packed_len,
+                mut block_unpackable,
+                mut block_unpackable_iter,
+            ),
+             (i, field)| {
                 let var: Expr = parse_str(format!("v{}", i).as_str()).unwrap();
                 let slf: Expr = parse_str(format!("self.{}", i).as_str()).unwrap();
 
-                if contains_attribute(&field, "map") {
-                    todo!("unnamed map is not implemented for derive macro; implement the traits manually")
-                } else if contains_attribute(&field, "array") {
-                    todo!("unnamed array is not implemented for derive macro; implement the traits manually")
-                } else {
-                    block_packable.stmts.push(parse_quote! {
-                        n += <#ty as ::msgpacker::Packable>::pack(&#slf, buf);
-                    });
-
-                    block_unpackable.stmts.push(parse_quote! {
-                        let #var = ::msgpacker::Unpackable::unpack(buf).map(|(nv, t)| {
-                            n += nv;
-                            buf = &buf[nv..];
-                            t
-                        })?;
-                    });
-
-                    block_unpackable_iter.stmts.push(parse_quote! {
-                        let #var = ::msgpacker::Unpackable::unpack_iter(bytes.by_ref()).map(|(nv, t)| {
-                            n += nv;
-                            t
-                        })?;
-                    });
-                }
+                let [packable, try_packable, packed_len, unpackable, unpackable_iter] =
+                    field_stmts(&field, &slf, &var);
+                block_packable.stmts.push(packable);
+                block_try_packable.stmts.push(try_packable);
+                block_packed_len.stmts.push(packed_len);
+                block_unpackable.stmts.push(unpackable);
+                block_unpackable_iter.stmts.push(unpackable_iter);
 
                 values.push(var);
 
-                (block_packable, block_unpackable, block_unpackable_iter)
+                (
+                    block_packable,
+                    block_try_packable,
+                    block_packed_len,
+                    block_unpackable,
+                    block_unpackable_iter,
+                )
             },
         );
 
@@ -265,6 +514,14 @@ fn impl_fields_unnamed(name: Ident, f: FieldsUnnamed) -> impl Into<TokenStream>
         return n;
     });
 
+    block_try_packable.stmts.push(parse_quote! {
+        return Ok(n);
+    });
+
+    block_packed_len.stmts.push(parse_quote! {
+        return n;
+    });
+
     block_unpackable.stmts.push(parse_quote! {
         return Ok((n, Self(#values)));
     });
@@ -277,8 +534,16 @@ fn impl_fields_unnamed(name: Ident, f: FieldsUnnamed) -> impl Into<TokenStream>
         impl ::msgpacker::Packable for #name {
             fn pack<T>(&self, buf: &mut T) -> usize
             where
-                T: Extend<u8>,
+                T: ::msgpacker::PackBuf,
                 #block_packable
+
+            fn try_pack<T>(&self, buf: &mut T) -> Result<usize, ::msgpacker::PackError>
+            where
+                T: ::msgpacker::PackBuf,
+                #block_try_packable
+
+            fn packed_len(&self) -> usize
+                #block_packed_len
         }
 
         impl ::msgpacker::Unpackable for #name {
@@ -300,10 +565,14 @@ fn impl_fields_unit(name: Ident) -> impl Into<TokenStream> {
         impl ::msgpacker::Packable for #name {
             fn pack<T>(&self, _buf: &mut T) -> usize
             where
-                T: Extend<u8>,
+                T: ::msgpacker::PackBuf,
             {
                 0
             }
+
+            fn packed_len(&self) -> usize {
+                0
+            }
         }
 
         impl ::msgpacker::Unpackable for #name {
@@ -333,6 +602,16 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
         }
     };
 
+    let mut block_try_packable: ExprMatch = parse_quote! {
+        match self {
+        }
+    };
+
+    let mut block_packed_len: ExprMatch = parse_quote! {
+        match self {
+        }
+    };
+
     let mut block_unpackable: ExprMatch = parse_quote! {
         match discriminant {
         }
@@ -354,6 +633,8 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
         match v.fields {
             Fields::Named(f) => {
                 let mut blk: Block = parse_str("{}").unwrap();
+                let mut blk_try: Block = parse_str("{}").unwrap();
+                let mut blk_len: Block = parse_str("{}").unwrap();
                 let mut blk_unpack: Block = parse_str("{}").unwrap();
                 let mut blk_unpack_iter: Block = parse_str("{}").unwrap();
                 let mut blk_unpack_fields: Punctuated<FieldValue, Token![,]> = Punctuated::new();
@@ -362,6 +643,14 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
                     n += (#discriminant as u32).pack(buf);
                 });
 
+                blk_try.stmts.push(parse_quote! {
+                    n += (#discriminant as u32).try_pack(buf)?;
+                });
+
+                blk_len.stmts.push(parse_quote! {
+                    n += (#discriminant as u32).packed_len();
+                });
+
                 f.named
                     .iter()
                     .filter_map(|n| n.ident.as_ref())
@@ -370,6 +659,14 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
                             n += #field.pack(buf);
                         });
 
+                        blk_try.stmts.push(parse_quote! {
+                            n += #field.try_pack(buf)?;
+                        });
+
+                        blk_len.stmts.push(parse_quote! {
+                            n += #field.packed_len();
+                        });
+
                         blk_unpack_fields.push(parse_quote! { #field });
 
                         blk_unpack.stmts.push(parse_quote! {
@@ -417,7 +714,15 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
                         }
                     });
 
+                let mut arm_try = arm.clone();
+                arm_try.body = Box::new(parse_quote! { #blk_try });
+
+                let mut arm_len = arm.clone();
+                arm_len.body = Box::new(parse_quote! { #blk_len });
+
                 block_packable.arms.push(arm);
+                block_try_packable.arms.push(arm_try);
+                block_packed_len.arms.push(arm_len);
 
                 blk_unpack.stmts.push(parse_quote! {
                     slf = #name::#ident { #blk_unpack_fields };
@@ -438,6 +743,8 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
 
             Fields::Unnamed(f) => {
                 let mut blk: Block = parse_str("{}").unwrap();
+                let mut blk_try: Block = parse_str("{}").unwrap();
+                let mut blk_len: Block = parse_str("{}").unwrap();
                 let mut blk_unpack: Block = parse_str("{}").unwrap();
                 let mut blk_unpack_iter: Block = parse_str("{}").unwrap();
 
@@ -445,6 +752,14 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
                     n += (#discriminant as u32).pack(buf);
                 });
 
+                blk_try.stmts.push(parse_quote! {
+                    n += (#discriminant as u32).try_pack(buf)?;
+                });
+
+                blk_len.stmts.push(parse_quote! {
+                    n += (#discriminant as u32).packed_len();
+                });
+
                 let mut tuple_arm: ExprTuple = parse_str("()").unwrap();
                 f.unnamed.iter().enumerate().for_each(|(ii, _field)| {
                     let ti: Expr = parse_str(format!("t{}", ii).as_str()).unwrap();
@@ -454,6 +769,14 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
                         n += #ti.pack(buf);
                     });
 
+                    blk_try.stmts.push(parse_quote! {
+                        n += #ti.try_pack(buf)?;
+                    });
+
+                    blk_len.stmts.push(parse_quote! {
+                        n += #ti.packed_len();
+                    });
+
                     blk_unpack.stmts.push(parse_quote! {
                         let #ti = Unpackable::unpack(buf).map(|(nv, t)| {
                             n += nv;
@@ -482,6 +805,14 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
                     #name::#ident #tuple_arm => #blk,
                 });
 
+                block_try_packable.arms.push(parse_quote! {
+                    #name::#ident #tuple_arm => #blk_try,
+                });
+
+                block_packed_len.arms.push(parse_quote! {
+                    #name::#ident #tuple_arm => #blk_len,
+                });
+
                 block_unpackable.arms.push(parse_quote! {
                     #discriminant => #blk_unpack,
                 });
@@ -498,6 +829,18 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
                     }
                 });
 
+                block_try_packable.arms.push(parse_quote! {
+                    #name::#ident => {
+                        n += (#discriminant as u32).try_pack(buf)?;
+                    }
+                });
+
+                block_packed_len.arms.push(parse_quote! {
+                    #name::#ident => {
+                        n += (#discriminant as u32).packed_len();
+                    }
+                });
+
                 block_unpackable.arms.push(parse_quote! {
                     #discriminant => slf = #name::#ident,
                 });
@@ -525,7 +868,7 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
         impl ::msgpacker::Packable for #name {
             fn pack<T>(&self, buf: &mut T) -> usize
             where
-                T: Extend<u8>,
+                T: ::msgpacker::PackBuf,
             {
                 let mut n = 0;
 
@@ -533,6 +876,25 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
 
                 return n;
             }
+
+            fn try_pack<T>(&self, buf: &mut T) -> Result<usize, ::msgpacker::PackError>
+            where
+                T: ::msgpacker::PackBuf,
+            {
+                let mut n = 0;
+
+                #block_try_packable;
+
+                Ok(n)
+            }
+
+            fn packed_len(&self) -> usize {
+                let mut n = 0;
+
+                #block_packed_len;
+
+                return n;
+            }
         }
 
         impl ::msgpacker::Unpackable for #name {
@@ -565,34 +927,52 @@ fn impl_fields_enum(name: Ident, v: Punctuated<Variant, Token![,]>) -> impl Into
     }
 }
 
+/// Implements `Packable` and `Unpackable` for a struct or enum, packing its fields in order.
+///
+/// Fields accept the following `#[msgpacker(..)]` directives:
+///
+/// - `map` and `array` pack a custom collection through its iterator as a map or an array.
+/// - `sorted = "map"` and `sorted = "set"` pack a collection as a map or an array with its entries
+///   sorted by their packed keys, so the output doesn't depend on the iteration order. Only the
+///   field itself is sorted: unordered collections nested in its entries are packed in their own
+///   iteration order, so use ordered collections for them when the output must be deterministic.
+/// - `unique` unpacks a map, failing if any key is duplicated.
+///
+/// Containers annotated with `#[msgpacker(ext = <type>)]` are packed as an extension.
 #[proc_macro_derive(MsgPacker, attributes(msgpacker))]
 pub fn msg_packer(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     let name = input.ident;
+    let ext = ext_attribute(&input.attrs);
     let data = input.data;
-    match data {
+    let packer: TokenStream = match data {
         Data::Struct(DataStruct {
             fields: Fields::Named(f),
             ..
-        }) => impl_fields_named(name, f).into(),
+        }) => impl_fields_named(name.clone(), f).into(),
 
         Data::Struct(DataStruct {
             fields: Fields::Unnamed(f),
             ..
-        }) => impl_fields_unnamed(name, f).into(),
+        }) => impl_fields_unnamed(name.clone(), f).into(),
 
         Data::Struct(DataStruct {
             fields: Fields::Unit,
             ..
-        }) => impl_fields_unit(name).into(),
+        }) => impl_fields_unit(name.clone()).into(),
 
-        Data::Enum(DataEnum { variants, .. }) => impl_fields_enum(name, variants).into(),
+        Data::Enum(DataEnum { variants, .. }) => impl_fields_enum(name.clone(), variants).into(),
 
         Data::Union(DataUnion { .. }) => {
             todo!(
                 "union support is not implemented for derive macro; implement the traits manually"
             )
         }
+    };
+
+    match ext {
+        Some(ty) => impl_ext(name, ty, packer),
+        None => packer,
     }
 }
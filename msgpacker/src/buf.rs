@@ -0,0 +1,105 @@
+/// A buffer the packed bytes are written into.
+///
+/// Writing whole slices at once allows the implementations to copy large `bin` and `str` payloads
+/// directly instead of going through a byte iterator.
+pub trait PackBuf {
+    /// Writes a single byte into the buffer.
+    fn put_u8(&mut self, b: u8);
+
+    /// Writes a slice of bytes into the buffer.
+    fn put_slice(&mut self, s: &[u8]);
+
+    /// Hints the buffer that at least `additional` bytes are about to be written.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+}
+
+/// Writes into the slice, advancing it past the written bytes.
+///
+/// # Panics
+///
+/// Panics if the slice is too short for the written bytes.
+impl PackBuf for &mut [u8] {
+    fn put_u8(&mut self, b: u8) {
+        self.put_slice(&[b]);
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        assert!(s.len() <= self.len(), "the buffer is too short");
+        let (l, r) = core::mem::take(self).split_at_mut(s.len());
+        l.copy_from_slice(s);
+        *self = r;
+    }
+}
+
+/// Adapter that writes into any [Extend] of bytes, as the sinks accepted by [crate::Packable::pack]
+/// before the introduction of [PackBuf].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PackExtend<E>(pub E);
+
+impl<E> PackBuf for PackExtend<E>
+where
+    E: Extend<u8>,
+{
+    fn put_u8(&mut self, b: u8) {
+        self.0.extend(Some(b));
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        self.0.extend(s.iter().copied());
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PackBuf for alloc::vec::Vec<u8> {
+    fn put_u8(&mut self, b: u8) {
+        self.push(b);
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        self.extend_from_slice(s);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        alloc::vec::Vec::reserve(self, additional);
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl PackBuf for bytes::BytesMut {
+    fn put_u8(&mut self, b: u8) {
+        bytes::BufMut::put_u8(self, b);
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        self.extend_from_slice(s);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        bytes::BytesMut::reserve(self, additional);
+    }
+}
+
+/// Adapter that writes into any [bytes::BufMut].
+///
+/// # Panics
+///
+/// Panics if the remaining capacity of the inner buffer is too short for the written bytes.
+#[cfg(feature = "bytes")]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PackBufMut<B>(pub B);
+
+#[cfg(feature = "bytes")]
+impl<B> PackBuf for PackBufMut<B>
+where
+    B: bytes::BufMut,
+{
+    fn put_u8(&mut self, b: u8) {
+        self.0.put_u8(b);
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        self.0.put_slice(s);
+    }
+}
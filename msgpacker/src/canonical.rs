@@ -0,0 +1,337 @@
+use super::{
+    helpers::{take_buffer, take_byte, take_num},
+    Error, Format, Unpackable,
+};
+
+/// Protocol reserved extension type of the timestamps.
+const TIMESTAMP: i8 = -1;
+
+/// Validation of the canonical form of a packed value.
+///
+/// A value is canonical when every header uses the shortest form available for its family:
+///
+/// - Integers use a fixint whenever possible, the smallest `UINT*` width for the other non-negative
+///   values, and the smallest `INT*` width for the other negative values. This is the encoding
+///   [crate::Packable] produces for the integer types, signed or not.
+/// - `str`, `bin`, array, map and extension headers use the smallest length prefix, including the
+///   fixext forms for the payload lengths that have one.
+/// - Timestamps use the smallest of the timestamp 32, 64 and 96 forms.
+/// - Maps don't contain duplicated keys.
+///
+/// Since canonical values have a single representation, keys are compared by their packed bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Canonical {
+    /// Rejects `FLOAT64` values that can be represented by a `FLOAT32` without loss of precision.
+    ///
+    /// This is disabled by default as `f64` always packs as `FLOAT64`.
+    pub minimal_floats: bool,
+    /// Maximum nesting of arrays and maps.
+    pub max_depth: usize,
+}
+
+impl Default for Canonical {
+    fn default() -> Self {
+        Self {
+            minimal_floats: false,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+impl Canonical {
+    /// Default maximum nesting of arrays and maps.
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+    /// Validates the value at the start of the buffer, returning the amount of bytes it takes.
+    pub fn validate(&self, buf: &[u8]) -> Result<usize, Error> {
+        let mut rem = buf;
+        self.value(&mut rem, 0)?;
+        Ok(buf.len() - rem.len())
+    }
+
+    /// Unpacks a value from the start of the buffer and validates the bytes it was read from,
+    /// returning the deserialized value and the amount of read bytes.
+    ///
+    /// Types such as tuples and derived structs are packed as several consecutive values; every
+    /// one of them is validated, and they must cover exactly the bytes read by the unpacking.
+    pub fn unpack<X>(&self, buf: &[u8]) -> Result<(usize, X), <X as Unpackable>::Error>
+    where
+        X: Unpackable,
+    {
+        let (n, x) = X::unpack(buf)?;
+        let mut values = &buf[..n];
+        while !values.is_empty() {
+            let c = self.validate(values).map_err(|e| match e {
+                Error::BufferTooShort => Error::NonCanonical,
+                e => e,
+            })?;
+            values = &values[c..];
+        }
+        Ok((n, x))
+    }
+
+    fn value(&self, buf: &mut &[u8], depth: usize) -> Result<(), Error> {
+        if depth > self.max_depth {
+            return Err(Error::MaxDepthExceeded);
+        }
+        let format = take_byte(buf)?;
+        match format {
+            0x00..=Format::POSITIVE_FIXINT | 0xe0..=0xff => Ok(()),
+            Format::NIL | Format::TRUE | Format::FALSE => Ok(()),
+            Format::UINT8 => minimal(take_byte(buf)? > Format::POSITIVE_FIXINT),
+            Format::UINT16 => minimal(take_num(buf, u16::from_be_bytes)? > u8::MAX as u16),
+            Format::UINT32 => minimal(take_num(buf, u32::from_be_bytes)? > u16::MAX as u32),
+            Format::UINT64 => minimal(take_num(buf, u64::from_be_bytes)? > u32::MAX as u64),
+            Format::INT8 => minimal((take_byte(buf)? as i8) < -32),
+            Format::INT16 => minimal(take_num(buf, i16::from_be_bytes)? < i8::MIN as i16),
+            Format::INT32 => minimal(take_num(buf, i32::from_be_bytes)? < i16::MIN as i32),
+            Format::INT64 => minimal(take_num(buf, i64::from_be_bytes)? < i32::MIN as i64),
+            Format::FLOAT32 => take_num(buf, f32::from_be_bytes).map(|_| ()),
+            Format::FLOAT64 => {
+                let v = take_num(buf, f64::from_be_bytes)?;
+                minimal(!self.minimal_floats || ((v as f32) as f64).to_bits() != v.to_bits())
+            }
+            0xa0..=0xbf => take_buffer(buf, format as usize & 0x1f).map(|_| ()),
+            Format::STR8 => {
+                let len = take_byte(buf)? as usize;
+                minimal(len > 31)?;
+                take_buffer(buf, len).map(|_| ())
+            }
+            Format::STR16 | Format::BIN16 => {
+                let len = take_num(buf, u16::from_be_bytes)? as usize;
+                minimal(len > u8::MAX as usize)?;
+                take_buffer(buf, len).map(|_| ())
+            }
+            Format::STR32 | Format::BIN32 => {
+                let len = take_num(buf, u32::from_be_bytes)? as usize;
+                minimal(len > u16::MAX as usize)?;
+                take_buffer(buf, len).map(|_| ())
+            }
+            Format::BIN8 => {
+                let len = take_byte(buf)? as usize;
+                take_buffer(buf, len).map(|_| ())
+            }
+            0x90..=0x9f => self.array(buf, format as usize & 0x0f, depth),
+            Format::ARRAY16 => {
+                let len = take_num(buf, u16::from_be_bytes)? as usize;
+                minimal(len > 15)?;
+                self.array(buf, len, depth)
+            }
+            Format::ARRAY32 => {
+                let len = take_num(buf, u32::from_be_bytes)? as usize;
+                minimal(len > u16::MAX as usize)?;
+                self.array(buf, len, depth)
+            }
+            0x80..=0x8f => self.map(buf, format as usize & 0x0f, depth),
+            Format::MAP16 => {
+                let len = take_num(buf, u16::from_be_bytes)? as usize;
+                minimal(len > 15)?;
+                self.map(buf, len, depth)
+            }
+            Format::MAP32 => {
+                let len = take_num(buf, u32::from_be_bytes)? as usize;
+                minimal(len > u16::MAX as usize)?;
+                self.map(buf, len, depth)
+            }
+            Format::FIXEXT1 => ext(buf, 1),
+            Format::FIXEXT2 => ext(buf, 2),
+            Format::FIXEXT4 => ext(buf, 4),
+            Format::FIXEXT8 => ext(buf, 8),
+            Format::FIXEXT16 => ext(buf, 16),
+            Format::EXT8 => {
+                let len = take_byte(buf)? as usize;
+                minimal(!matches!(len, 1 | 2 | 4 | 8 | 16))?;
+                ext(buf, len)
+            }
+            Format::EXT16 => {
+                let len = take_num(buf, u16::from_be_bytes)? as usize;
+                minimal(len > u8::MAX as usize)?;
+                ext(buf, len)
+            }
+            Format::EXT32 => {
+                let len = take_num(buf, u32::from_be_bytes)? as usize;
+                minimal(len > u16::MAX as usize)?;
+                ext(buf, len)
+            }
+            _ => Err(Error::UnexpectedFormatTag),
+        }
+    }
+
+    fn array(&self, buf: &mut &[u8], len: usize, depth: usize) -> Result<(), Error> {
+        for _ in 0..len {
+            self.value(buf, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    fn map(&self, buf: &mut &[u8], len: usize, depth: usize) -> Result<(), Error> {
+        #[cfg(feature = "alloc")]
+        {
+            // every key takes at least one byte
+            let mut keys = alloc::vec::Vec::with_capacity(len.min(buf.len()));
+            for _ in 0..len {
+                keys.push(self.entry(buf, depth)?);
+            }
+            keys.sort_unstable();
+            if keys.windows(2).any(|w| w[0] == w[1]) {
+                return Err(Error::DuplicateMapKey);
+            }
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            // Without an allocator the keys can't be stored, so the validated entries are walked
+            // again in blocks of keys, and every block is compared with the keys that follow it.
+            const BLOCK: usize = 32;
+
+            let mut entries = *buf;
+            for _ in 0..len {
+                self.entry(buf, depth)?;
+            }
+            let mut keys: [&[u8]; BLOCK] = [&[]; BLOCK];
+            let mut i = 0;
+            while i < len {
+                let block = BLOCK.min(len - i);
+                for k in 0..block {
+                    keys[k] = skip_entry(&mut entries)?;
+                    if keys[..k].contains(&keys[k]) {
+                        return Err(Error::DuplicateMapKey);
+                    }
+                }
+                i += block;
+                let mut others = entries;
+                for _ in i..len {
+                    let key = skip_entry(&mut others)?;
+                    if keys[..block].contains(&key) {
+                        return Err(Error::DuplicateMapKey);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates a map entry, returning the packed bytes of its key.
+    fn entry<'a>(&self, buf: &mut &'a [u8], depth: usize) -> Result<&'a [u8], Error> {
+        let start = *buf;
+        self.value(buf, depth + 1)?;
+        let key = &start[..start.len() - buf.len()];
+        self.value(buf, depth + 1)?;
+        Ok(key)
+    }
+}
+
+/// Skips a validated map entry, returning the packed bytes of its key.
+#[cfg(not(feature = "alloc"))]
+fn skip_entry<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], Error> {
+    let start = *buf;
+    skip(buf)?;
+    let key = &start[..start.len() - buf.len()];
+    skip(buf)?;
+    Ok(key)
+}
+
+/// Skips a validated value, reading only its headers.
+#[cfg(not(feature = "alloc"))]
+fn skip(buf: &mut &[u8]) -> Result<(), Error> {
+    let format = take_byte(buf)?;
+    let (len, values) = match format {
+        0x00..=Format::POSITIVE_FIXINT | 0xe0..=0xff => (0, 0),
+        Format::NIL | Format::TRUE | Format::FALSE => (0, 0),
+        Format::UINT8 | Format::INT8 => (1, 0),
+        Format::UINT16 | Format::INT16 => (2, 0),
+        Format::UINT32 | Format::INT32 | Format::FLOAT32 => (4, 0),
+        Format::UINT64 | Format::INT64 | Format::FLOAT64 => (8, 0),
+        0xa0..=0xbf => (format as usize & 0x1f, 0),
+        Format::STR8 | Format::BIN8 => (take_byte(buf)? as usize, 0),
+        Format::STR16 | Format::BIN16 => (take_num(buf, u16::from_be_bytes)? as usize, 0),
+        Format::STR32 | Format::BIN32 => (take_num(buf, u32::from_be_bytes)? as usize, 0),
+        0x90..=0x9f => (0, format as usize & 0x0f),
+        Format::ARRAY16 => (0, take_num(buf, u16::from_be_bytes)? as usize),
+        Format::ARRAY32 => (0, take_num(buf, u32::from_be_bytes)? as usize),
+        0x80..=0x8f => (0, 2 * (format as usize & 0x0f)),
+        Format::MAP16 => (0, 2 * take_num(buf, u16::from_be_bytes)? as usize),
+        Format::MAP32 => (0, 2 * take_num(buf, u32::from_be_bytes)? as usize),
+        Format::FIXEXT1 => (2, 0),
+        Format::FIXEXT2 => (3, 0),
+        Format::FIXEXT4 => (5, 0),
+        Format::FIXEXT8 => (9, 0),
+        Format::FIXEXT16 => (17, 0),
+        Format::EXT8 => (take_byte(buf)? as usize + 1, 0),
+        Format::EXT16 => (take_num(buf, u16::from_be_bytes)? as usize + 1, 0),
+        Format::EXT32 => (take_num(buf, u32::from_be_bytes)? as usize + 1, 0),
+        _ => return Err(Error::UnexpectedFormatTag),
+    };
+    take_buffer(buf, len)?;
+    for _ in 0..values {
+        skip(buf)?;
+    }
+    Ok(())
+}
+
+fn minimal(canonical: bool) -> Result<(), Error> {
+    if canonical {
+        Ok(())
+    } else {
+        Err(Error::NonCanonical)
+    }
+}
+
+fn ext(buf: &mut &[u8], len: usize) -> Result<(), Error> {
+    let t = take_byte(buf)? as i8;
+    let mut data = take_buffer(buf, len)?;
+    if t != TIMESTAMP {
+        return Ok(());
+    }
+    let nanos = match len {
+        4 => return Ok(()),
+        8 => {
+            let data = take_num(&mut data, u64::from_be_bytes)?;
+            let secs = data & ((1u64 << 34) - 1);
+            let nanos = (data >> 34) as u32;
+            minimal(nanos != 0 || secs > u32::MAX as u64)?;
+            nanos
+        }
+        12 => {
+            let nanos = take_num(&mut data, u32::from_be_bytes)?;
+            let secs = take_num(&mut data, i64::from_be_bytes)?;
+            minimal(!(0..1i64 << 34).contains(&secs))?;
+            nanos
+        }
+        _ => return Err(Error::InvalidExtension),
+    };
+    if nanos >= 1_000_000_000 {
+        return Err(Error::InvalidExtension);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicated_keys() {
+        let canonical = Canonical::default();
+        assert_eq!(canonical.validate(&[0x82, 1, 2, 3, 4]), Ok(5));
+        assert_eq!(
+            canonical.validate(&[0x82, 1, 2, 1, 4]),
+            Err(Error::DuplicateMapKey)
+        );
+        assert_eq!(
+            canonical.validate(&[0x82, 0x91, 1, 0x80, 0x91, 1, 0xc0]),
+            Err(Error::DuplicateMapKey)
+        );
+
+        // keys on both sides of a block boundary
+        let mut bytes = [0u8; 3 + 2 * 40];
+        bytes[..3].copy_from_slice(&[Format::MAP16, 0, 40]);
+        for (i, entry) in bytes[3..].chunks_mut(2).enumerate() {
+            entry[0] = i as u8;
+        }
+        assert_eq!(canonical.validate(&bytes), Ok(bytes.len()));
+        bytes[3 + 2 * 35] = 3;
+        assert_eq!(canonical.validate(&bytes), Err(Error::DuplicateMapKey));
+    }
+}
@@ -1,6 +1,6 @@
 use core::fmt;
 
-/// Deserialization errors for the protocol implementation.
+/// Serialization and deserialization errors for the protocol implementation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Error {
     /// The provided buffer is too short and yielded an unexpected EOF.
@@ -15,6 +15,24 @@ pub enum Error {
     UnexpectedFormatTag,
     /// The provided bin length is not valid.
     UnexpectedBinLength,
+    /// The value is not encoded in its canonical form.
+    NonCanonical,
+    /// The map contains the same key more than once.
+    DuplicateMapKey,
+    /// The nesting of arrays and maps, or of unpacked pointers, exceeds the allowed depth.
+    MaxDepthExceeded,
+    /// The integer is not a valid Unicode scalar value.
+    InvalidChar,
+    /// The integer is zero, but the static type is non-zero.
+    UnexpectedZero,
+    /// The nanoseconds of the duration are not smaller than one second.
+    InvalidDuration,
+    /// The C string contains an interior nul byte.
+    InvalidCString,
+    /// The collection has more elements than the capacity of the static type.
+    CapacityOverflow,
+    /// The string is not a valid decimal number.
+    InvalidDecimal,
 }
 
 impl fmt::Display for Error {
@@ -25,3 +43,26 @@ impl fmt::Display for Error {
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
+
+/// Serialization errors for the protocol implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackError {
+    /// The length of a buffer, string or collection exceeds the protocol limit of `u32::MAX`.
+    LengthOverflow,
+    /// The timestamp is outside the range of the protocol, which counts the seconds since the Unix
+    /// epoch as an `i64`.
+    TimestampOverflow,
+    /// The path or OS string is not valid UTF-8, and its bytes are not portable on this platform.
+    InvalidUtf8,
+    /// The output buffer is too short for the packed value.
+    BufferTooShort,
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackError {}
@@ -0,0 +1,254 @@
+use super::{
+    helpers::{
+        ext_header_len, pack_ext_header, take_buffer, take_buffer_iter, take_ext_header,
+        take_ext_header_iter,
+    },
+    Error, Extension, PackBuf, PackError, Packable, Unpackable,
+};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::any::Any;
+
+/// A custom extension type, packed with its own extension type id.
+///
+/// Implementors can be packed and unpacked as extensions via [Ext], or the functions [pack_ext]
+/// and [unpack_ext]. The `MsgPacker` derive macro implements this trait, as well as [Packable]
+/// and [Unpackable], for types annotated with `#[msgpacker(ext = <type>)]`; the payload is then the
+/// packed fields of the type.
+///
+/// The protocol reserves the negative types; custom extensions should use `0..=127`.
+pub trait MsgPackExt: Sized {
+    /// Extension type id.
+    const TYPE: i8;
+
+    /// Returns the amount of bytes [MsgPackExt::encode] will write.
+    fn payload_len(&self) -> usize;
+
+    /// Writes the payload of the extension into the buffer.
+    fn encode<T>(&self, buf: &mut T)
+    where
+        T: PackBuf;
+
+    /// Decodes the extension from its payload.
+    fn decode(payload: &[u8]) -> Result<Self, Error>;
+}
+
+/// Packs a custom extension into the buffer, returning the amount of written bytes.
+#[allow(unreachable_code)]
+pub fn pack_ext<T, X>(buf: &mut T, x: &X) -> usize
+where
+    T: PackBuf,
+    X: MsgPackExt,
+{
+    if let Ok(n) = try_pack_ext(buf, x) {
+        return n;
+    }
+    #[cfg(feature = "strict")]
+    panic!("strict serialization enabled; the buffer is too large");
+    0
+}
+
+/// Packs a custom extension into the buffer, returning the amount of written bytes.
+///
+/// Fails with [PackError::LengthOverflow] without writing anything if the payload is too large to
+/// be represented by the protocol.
+pub fn try_pack_ext<T, X>(buf: &mut T, x: &X) -> Result<usize, PackError>
+where
+    T: PackBuf,
+    X: MsgPackExt,
+{
+    let len = x.payload_len();
+    let n = pack_ext_header(buf, X::TYPE, len)?;
+    buf.reserve(len);
+    x.encode(buf);
+    Ok(n + len)
+}
+
+/// Returns the amount of bytes [pack_ext] will write for the provided extension.
+pub fn packed_ext_len<X>(x: &X) -> usize
+where
+    X: MsgPackExt,
+{
+    let len = x.payload_len();
+    match ext_header_len(len) {
+        0 => 0,
+        n => n + len,
+    }
+}
+
+/// Unpacks a custom extension from the buffer, returning the deserialized value and the amount of
+/// read bytes.
+///
+/// Fails with [Error::InvalidExtension] if the extension type doesn't match [MsgPackExt::TYPE].
+pub fn unpack_ext<X>(mut buf: &[u8]) -> Result<(usize, X), Error>
+where
+    X: MsgPackExt,
+{
+    let (n, t, len) = take_ext_header(&mut buf)?;
+    if t != X::TYPE {
+        return Err(Error::InvalidExtension);
+    }
+    let payload = take_buffer(&mut buf, len)?;
+    Ok((n + len, X::decode(payload)?))
+}
+
+/// Unpacks a custom extension from the iterator, returning the deserialized value and the amount
+/// of read bytes.
+///
+/// Fails with [Error::InvalidExtension] if the extension type doesn't match [MsgPackExt::TYPE].
+pub fn unpack_ext_iter<I, X>(iter: I) -> Result<(usize, X), Error>
+where
+    I: IntoIterator<Item = u8>,
+    X: MsgPackExt,
+{
+    let mut bytes = iter.into_iter();
+    let (n, t, len) = take_ext_header_iter(bytes.by_ref())?;
+    if t != X::TYPE {
+        return Err(Error::InvalidExtension);
+    }
+    let payload = take_buffer_iter(bytes, len)?;
+    Ok((n + len, X::decode(&payload)?))
+}
+
+/// Wrapper that packs and unpacks a [MsgPackExt] as an extension.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ext<X>(pub X);
+
+impl<X> Packable for Ext<X>
+where
+    X: MsgPackExt,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        pack_ext(buf, &self.0)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        try_pack_ext(buf, &self.0)
+    }
+
+    fn packed_len(&self) -> usize {
+        packed_ext_len(&self.0)
+    }
+}
+
+impl<X> Unpackable for Ext<X>
+where
+    X: MsgPackExt,
+{
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        unpack_ext(buf).map(|(n, x)| (n, Ext(x)))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        unpack_ext_iter(bytes).map(|(n, x)| (n, Ext(x)))
+    }
+}
+
+impl Extension {
+    /// Encodes a custom extension.
+    pub fn encode<X>(x: &X) -> Self
+    where
+        X: MsgPackExt,
+    {
+        let mut payload = Vec::with_capacity(x.payload_len());
+        x.encode(&mut payload);
+        Extension::Ext(X::TYPE, payload)
+    }
+
+    /// Decodes a custom extension.
+    ///
+    /// Fails with [Error::InvalidExtension] if the extension type doesn't match [MsgPackExt::TYPE].
+    pub fn decode<X>(&self) -> Result<X, Error>
+    where
+        X: MsgPackExt,
+    {
+        match self {
+            Extension::Ext(t, payload) if *t == X::TYPE => X::decode(payload),
+            _ => Err(Error::InvalidExtension),
+        }
+    }
+}
+
+type Decoder = fn(&[u8]) -> Result<Box<dyn Any>, Error>;
+
+/// Runtime registry of custom extension types.
+///
+/// Dynamically typed payloads unpack their extensions as [Extension]; the registry decodes them
+/// into the registered [MsgPackExt] types, which can then be recovered via [Box::downcast].
+#[derive(Debug, Default, Clone)]
+pub struct ExtRegistry {
+    decoders: BTreeMap<i8, Decoder>,
+}
+
+impl ExtRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a custom extension type, replacing any type previously registered with the same
+    /// id.
+    pub fn register<X>(&mut self) -> &mut Self
+    where
+        X: MsgPackExt + 'static,
+    {
+        self.decoders.insert(X::TYPE, |payload| {
+            X::decode(payload).map(|x| Box::new(x) as Box<dyn Any>)
+        });
+        self
+    }
+
+    /// Returns `true` if a custom extension is registered with the provided type id.
+    pub fn contains(&self, t: i8) -> bool {
+        self.decoders.contains_key(&t)
+    }
+
+    /// Decodes an extension into its registered type.
+    ///
+    /// Returns `None` if the extension type isn't registered.
+    pub fn decode(&self, ext: &Extension) -> Option<Result<Box<dyn Any>, Error>> {
+        match ext {
+            Extension::Ext(t, payload) => self.decoders.get(t).map(|d| d(payload)),
+            Extension::Timestamp(_) => None,
+        }
+    }
+}
+
+/// Packs the payload of an extension implemented by the derive macro.
+#[doc(hidden)]
+pub trait PackPayload {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf;
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        Ok(self.pack(buf))
+    }
+
+    fn packed_len(&self) -> usize;
+}
+
+/// Unpacks the payload of an extension implemented by the derive macro.
+#[doc(hidden)]
+pub trait UnpackPayload: Sized {
+    type Error: From<Error>;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error>;
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>;
+}
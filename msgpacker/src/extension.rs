@@ -1,10 +1,11 @@
 use super::{
     error::Error,
-    helpers::{take_buffer, take_buffer_iter, take_byte, take_byte_iter, take_num, take_num_iter},
-    Format, Packable, Unpackable,
+    helpers::{take_buffer, take_buffer_iter, take_ext_header, take_ext_header_iter},
+    timestamp::{decode_timestamp, pack_timestamp, timestamp_len, TIMESTAMP},
+    Format, PackBuf, PackError, Packable, Unpackable,
 };
-use alloc::{vec, vec::Vec};
-use core::{iter, time::Duration};
+use alloc::vec::Vec;
+use core::time::Duration;
 
 /// Custom extension definition as reference to a bytes source.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -17,88 +18,80 @@ pub enum Extension {
 
 impl Extension {
     /// Protocol constant for a timestamp extension
-    pub const TIMESTAMP: i8 = -1;
+    pub const TIMESTAMP: i8 = TIMESTAMP;
+
+    /// Creates an extension from its type and payload, decoding timestamps.
+    ///
+    /// Timestamps before the Unix epoch can't be represented by a [Duration], so they are kept as
+    /// an [Extension::Ext] with their raw payload; use `std::time::SystemTime` to decode them.
+    fn from_payload(t: i8, data: &[u8]) -> Result<Self, Error> {
+        if t != Self::TIMESTAMP {
+            return Ok(Extension::Ext(t, data.to_vec()));
+        }
+        match decode_timestamp(data)? {
+            (secs, nanos) if secs >= 0 => {
+                Ok(Extension::Timestamp(Duration::new(secs as u64, nanos)))
+            }
+            _ => Ok(Extension::Ext(t, data.to_vec())),
+        }
+    }
 }
 
 impl Packable for Extension {
     #[allow(unreachable_code)]
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         match self {
             Extension::Ext(t, b) if b.len() == 1 => {
-                buf.extend(
-                    iter::once(Format::FIXEXT1)
-                        .chain(iter::once(*t as u8))
-                        .chain(iter::once(b[0])),
-                );
+                buf.put_slice(&[Format::FIXEXT1, *t as u8, b[0]]);
                 3
             }
 
             Extension::Ext(t, b) if b.len() == 2 => {
-                buf.extend(
-                    iter::once(Format::FIXEXT2)
-                        .chain(iter::once(*t as u8))
-                        .chain(b.iter().copied()),
-                );
+                buf.put_slice(&[Format::FIXEXT2, *t as u8]);
+                buf.put_slice(b);
                 4
             }
 
             Extension::Ext(t, b) if b.len() == 4 => {
-                buf.extend(
-                    iter::once(Format::FIXEXT4)
-                        .chain(iter::once(*t as u8))
-                        .chain(b.iter().copied()),
-                );
+                buf.put_slice(&[Format::FIXEXT4, *t as u8]);
+                buf.put_slice(b);
                 6
             }
 
             Extension::Ext(t, b) if b.len() == 8 => {
-                buf.extend(
-                    iter::once(Format::FIXEXT8)
-                        .chain(iter::once(*t as u8))
-                        .chain(b.iter().copied()),
-                );
+                buf.put_slice(&[Format::FIXEXT8, *t as u8]);
+                buf.put_slice(b);
                 10
             }
 
             Extension::Ext(t, b) if b.len() == 16 => {
-                buf.extend(
-                    iter::once(Format::FIXEXT16)
-                        .chain(iter::once(*t as u8))
-                        .chain(b.iter().copied()),
-                );
+                buf.put_slice(&[Format::FIXEXT16, *t as u8]);
+                buf.put_slice(b);
                 18
             }
 
             Extension::Ext(t, b) if b.len() <= u8::MAX as usize => {
-                buf.extend(
-                    iter::once(Format::EXT8)
-                        .chain(iter::once(b.len() as u8))
-                        .chain(iter::once(*t as u8))
-                        .chain(b.iter().copied()),
-                );
+                buf.put_slice(&[Format::EXT8, b.len() as u8, *t as u8]);
+                buf.put_slice(b);
                 3 + b.len()
             }
 
             Extension::Ext(t, b) if b.len() <= u16::MAX as usize => {
-                buf.extend(
-                    iter::once(Format::EXT16)
-                        .chain((b.len() as u16).to_be_bytes().iter().copied())
-                        .chain(iter::once(*t as u8))
-                        .chain(b.iter().copied()),
-                );
+                buf.put_u8(Format::EXT16);
+                buf.put_slice(&(b.len() as u16).to_be_bytes());
+                buf.put_u8(*t as u8);
+                buf.put_slice(b);
                 4 + b.len()
             }
 
             Extension::Ext(t, b) if b.len() <= u32::MAX as usize => {
-                buf.extend(
-                    iter::once(Format::EXT32)
-                        .chain((b.len() as u32).to_be_bytes().iter().copied())
-                        .chain(iter::once(*t as u8))
-                        .chain(b.iter().copied()),
-                );
+                buf.put_u8(Format::EXT32);
+                buf.put_slice(&(b.len() as u32).to_be_bytes());
+                buf.put_u8(*t as u8);
+                buf.put_slice(b);
                 6 + b.len()
             }
 
@@ -108,44 +101,46 @@ impl Packable for Extension {
                 0
             }
 
-            Extension::Timestamp(d) if d.as_secs() <= u32::MAX as u64 && d.subsec_nanos() == 0 => {
-                buf.extend(
-                    iter::once(Format::FIXEXT4)
-                        .chain(iter::once(Self::TIMESTAMP as u8))
-                        .chain((d.as_secs() as u32).to_be_bytes().iter().copied()),
-                );
-                6
+            Extension::Timestamp(d) if d.as_secs() <= i64::MAX as u64 => {
+                pack_timestamp(buf, d.as_secs() as i64, d.subsec_nanos())
             }
 
-            Extension::Timestamp(d)
-                if d.as_secs() < 1u64 << 34 && d.subsec_nanos() < 1u32 << 30 =>
-            {
-                let secs = d.as_secs();
-                let secs_nanos = ((secs >> 32) & 0b11) as u32;
-                let secs = secs as u32;
-
-                let nanos = d.subsec_nanos() << 2;
-                let nanos = nanos | secs_nanos;
-
-                buf.extend(
-                    iter::once(Format::FIXEXT8)
-                        .chain(iter::once(Self::TIMESTAMP as u8))
-                        .chain(nanos.to_be_bytes().iter().copied())
-                        .chain(secs.to_be_bytes().iter().copied()),
-                );
-                10
+            Extension::Timestamp(_) => {
+                #[cfg(feature = "strict")]
+                panic!("strict serialization enabled; the timestamp is out of range");
+                0
             }
+        }
+    }
 
-            Extension::Timestamp(d) => {
-                buf.extend(
-                    iter::once(Format::EXT8)
-                        .chain(iter::once(12))
-                        .chain(iter::once(Self::TIMESTAMP as u8))
-                        .chain(d.subsec_nanos().to_be_bytes().iter().copied())
-                        .chain(d.as_secs().to_be_bytes().iter().copied()),
-                );
-                15
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        match self {
+            Extension::Ext(_, b) if b.len() > u32::MAX as usize => Err(PackError::LengthOverflow),
+            Extension::Timestamp(d) if d.as_secs() > i64::MAX as u64 => {
+                Err(PackError::TimestampOverflow)
             }
+            _ => Ok(self.pack(buf)),
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        match self {
+            Extension::Ext(_, b) if b.len() == 1 => 3,
+            Extension::Ext(_, b) if b.len() == 2 => 4,
+            Extension::Ext(_, b) if b.len() == 4 => 6,
+            Extension::Ext(_, b) if b.len() == 8 => 10,
+            Extension::Ext(_, b) if b.len() == 16 => 18,
+            Extension::Ext(_, b) if b.len() <= u8::MAX as usize => 3 + b.len(),
+            Extension::Ext(_, b) if b.len() <= u16::MAX as usize => 4 + b.len(),
+            Extension::Ext(_, b) if b.len() <= u32::MAX as usize => 6 + b.len(),
+            Extension::Ext(_, _) => 0,
+            Extension::Timestamp(d) if d.as_secs() <= i64::MAX as u64 => {
+                timestamp_len(d.as_secs() as i64, d.subsec_nanos())
+            }
+            Extension::Timestamp(_) => 0,
         }
     }
 }
@@ -154,73 +149,9 @@ impl Unpackable for Extension {
     type Error = Error;
 
     fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
-        let format = take_byte(&mut buf)?;
-        match format {
-            Format::FIXEXT1 => {
-                let t = take_byte(&mut buf)? as i8;
-                let x = take_byte(&mut buf)?;
-                Ok((3, Extension::Ext(t, vec![x])))
-            }
-            Format::FIXEXT2 => {
-                let t = take_byte(&mut buf)? as i8;
-                let b = take_buffer(&mut buf, 2)?;
-                Ok((4, Extension::Ext(t, b.to_vec())))
-            }
-            Format::FIXEXT4 => {
-                let t = take_byte(&mut buf)? as i8;
-                if t == Self::TIMESTAMP {
-                    let secs = take_num(&mut buf, u32::from_be_bytes)?;
-                    Ok((6, Extension::Timestamp(Duration::from_secs(secs as u64))))
-                } else {
-                    let b = take_buffer(&mut buf, 4)?;
-                    Ok((6, Extension::Ext(t, b.to_vec())))
-                }
-            }
-            Format::FIXEXT8 => {
-                let t = take_byte(&mut buf)? as i8;
-                if t == Self::TIMESTAMP {
-                    let data = take_num(&mut buf, u64::from_be_bytes)?;
-
-                    let nanos = (data >> 34) as u32;
-                    let secs = data & ((1u64 << 34) - 1);
-
-                    Ok((10, Extension::Timestamp(Duration::new(secs, nanos))))
-                } else {
-                    let b = take_buffer(&mut buf, 8)?;
-                    Ok((10, Extension::Ext(t, b.to_vec())))
-                }
-            }
-            Format::FIXEXT16 => {
-                let t = take_byte(&mut buf)? as i8;
-                let b = take_buffer(&mut buf, 16)?;
-                Ok((18, Extension::Ext(t, b.to_vec())))
-            }
-            Format::EXT8 => {
-                let len = take_byte(&mut buf)? as usize;
-                let t = take_byte(&mut buf)? as i8;
-                if len == 12 && t == Self::TIMESTAMP {
-                    let nanos = take_num(&mut buf, u32::from_be_bytes)?;
-                    let secs = take_num(&mut buf, u64::from_be_bytes)?;
-                    Ok((15, Extension::Timestamp(Duration::new(secs, nanos))))
-                } else {
-                    let b = take_buffer(&mut buf, len)?;
-                    Ok((3 + len, Extension::Ext(t, b.to_vec())))
-                }
-            }
-            Format::EXT16 => {
-                let len = take_num(&mut buf, u16::from_be_bytes)? as usize;
-                let t = take_byte(&mut buf)? as i8;
-                let b = take_buffer(&mut buf, len)?;
-                Ok((4 + len, Extension::Ext(t, b.to_vec())))
-            }
-            Format::EXT32 => {
-                let len = take_num(&mut buf, u32::from_be_bytes)? as usize;
-                let t = take_byte(&mut buf)? as i8;
-                let b = take_buffer(&mut buf, len)?;
-                Ok((6 + len, Extension::Ext(t, b.to_vec())))
-            }
-            _ => Err(Error::InvalidExtension),
-        }
+        let (n, t, len) = take_ext_header(&mut buf)?;
+        let data = take_buffer(&mut buf, len)?;
+        Ok((n + len, Self::from_payload(t, data)?))
     }
 
     fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
@@ -228,73 +159,9 @@ impl Unpackable for Extension {
         I: IntoIterator<Item = u8>,
     {
         let mut bytes = bytes.into_iter();
-        let format = take_byte_iter(bytes.by_ref())?;
-        match format {
-            Format::FIXEXT1 => {
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                let x = take_byte_iter(bytes.by_ref())?;
-                Ok((3, Extension::Ext(t, vec![x])))
-            }
-            Format::FIXEXT2 => {
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                let b = take_buffer_iter(bytes.by_ref(), 2)?;
-                Ok((4, Extension::Ext(t, b)))
-            }
-            Format::FIXEXT4 => {
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                if t == Self::TIMESTAMP {
-                    let secs = take_num_iter(bytes.by_ref(), u32::from_be_bytes)?;
-                    Ok((6, Extension::Timestamp(Duration::from_secs(secs as u64))))
-                } else {
-                    let b = take_buffer_iter(bytes.by_ref(), 4)?;
-                    Ok((6, Extension::Ext(t, b)))
-                }
-            }
-            Format::FIXEXT8 => {
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                if t == Self::TIMESTAMP {
-                    let data = take_num_iter(bytes.by_ref(), u64::from_be_bytes)?;
-
-                    let nanos = (data >> 34) as u32;
-                    let secs = data & ((1u64 << 34) - 1);
-
-                    Ok((10, Extension::Timestamp(Duration::new(secs, nanos))))
-                } else {
-                    let b = take_buffer_iter(bytes.by_ref(), 8)?;
-                    Ok((10, Extension::Ext(t, b)))
-                }
-            }
-            Format::FIXEXT16 => {
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                let b = take_buffer_iter(bytes.by_ref(), 16)?;
-                Ok((18, Extension::Ext(t, b)))
-            }
-            Format::EXT8 => {
-                let len = take_byte_iter(bytes.by_ref())? as usize;
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                if len == 12 && t == Self::TIMESTAMP {
-                    let nanos = take_num_iter(bytes.by_ref(), u32::from_be_bytes)?;
-                    let secs = take_num_iter(bytes.by_ref(), u64::from_be_bytes)?;
-                    Ok((15, Extension::Timestamp(Duration::new(secs, nanos))))
-                } else {
-                    let b = take_buffer_iter(bytes.by_ref(), len)?;
-                    Ok((3 + len, Extension::Ext(t, b)))
-                }
-            }
-            Format::EXT16 => {
-                let len = take_num_iter(bytes.by_ref(), u16::from_be_bytes)? as usize;
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                let b = take_buffer_iter(bytes.by_ref(), len)?;
-                Ok((4 + len, Extension::Ext(t, b)))
-            }
-            Format::EXT32 => {
-                let len = take_num_iter(bytes.by_ref(), u32::from_be_bytes)? as usize;
-                let t = take_byte_iter(bytes.by_ref())? as i8;
-                let b = take_buffer_iter(bytes.by_ref(), len)?;
-                Ok((6 + len, Extension::Ext(t, b)))
-            }
-            _ => Err(Error::InvalidExtension),
-        }
+        let (n, t, len) = take_ext_header_iter(bytes.by_ref())?;
+        let data = take_buffer_iter(bytes, len)?;
+        Ok((n + len, Self::from_payload(t, &data)?))
     }
 }
 
@@ -311,18 +178,71 @@ mod tests {
             }
             let x = Extension::Ext(t, b);
             let mut bytes = vec![];
-            x.pack(&mut bytes);
+            let n = x.pack(&mut bytes);
+            assert_eq!(n, x.packed_len());
             let (_, y) = Extension::unpack(&bytes).unwrap();
             assert_eq!(x, y);
         }
 
         #[test]
-        fn extension_duration(d: Duration) {
-            let x = Extension::Timestamp(d);
+        fn extension_duration(secs in 0..=i64::MAX as u64, nanos in 0..1_000_000_000u32) {
+            let x = Extension::Timestamp(Duration::new(secs, nanos));
             let mut bytes = vec![];
-            x.pack(&mut bytes);
+            let n = x.pack(&mut bytes);
+            assert_eq!(n, x.packed_len());
             let (_, y) = Extension::unpack(&bytes).unwrap();
             assert_eq!(x, y);
         }
     }
+
+    #[test]
+    fn extension_invalid_timestamp() {
+        let nanos = 1_000_000_000u64 << 34;
+        let mut bytes = vec![Format::FIXEXT8, 0xff];
+        bytes.extend(nanos.to_be_bytes());
+        assert_eq!(Extension::unpack(&bytes), Err(Error::InvalidExtension));
+        assert_eq!(Extension::unpack_iter(bytes), Err(Error::InvalidExtension));
+
+        let mut bytes = vec![Format::EXT8, 12, 0xff];
+        bytes.extend(0u32.to_be_bytes());
+        bytes.extend((-1i64).to_be_bytes());
+        let ext = Extension::Ext(Extension::TIMESTAMP, bytes[3..].to_vec());
+        assert_eq!(Extension::unpack(&bytes), Ok((15, ext)));
+
+        let mut bytes = vec![Format::EXT8, 12, 0xff];
+        bytes.extend(0u32.to_be_bytes());
+        bytes.extend((u32::MAX as i64 + 1).to_be_bytes());
+        assert_eq!(
+            Extension::unpack(&bytes),
+            Ok((15, Extension::Timestamp(Duration::from_secs(1 << 32))))
+        );
+
+        for bytes in [
+            vec![Format::FIXEXT1, 0xff, 0],
+            vec![
+                Format::FIXEXT16,
+                0xff,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ],
+            vec![Format::EXT8, 3, 0xff, 0, 0, 0],
+        ] {
+            assert_eq!(Extension::unpack(&bytes), Err(Error::InvalidExtension));
+            assert_eq!(Extension::unpack_iter(bytes), Err(Error::InvalidExtension));
+        }
+    }
 }
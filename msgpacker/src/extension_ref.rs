@@ -0,0 +1,116 @@
+use super::{
+    helpers::{ext_header_len, pack_ext_header, take_buffer, take_ext_header},
+    Error, PackBuf, PackError, Packable,
+};
+
+/// Custom extension definition as reference to a bytes source.
+///
+/// Unlike [crate::Extension], the payload is borrowed from the buffer and is not interpreted, so it
+/// is available without `alloc`. Timestamps are represented as their raw payload, with the
+/// [ExtensionRef::TIMESTAMP] type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtensionRef<'a> {
+    /// Extension type.
+    pub type_id: i8,
+    /// Payload of the extension.
+    pub data: &'a [u8],
+}
+
+impl<'a> ExtensionRef<'a> {
+    /// Protocol constant for a timestamp extension
+    pub const TIMESTAMP: i8 = -1;
+
+    /// Creates a new extension reference.
+    pub const fn new(type_id: i8, data: &'a [u8]) -> Self {
+        Self { type_id, data }
+    }
+
+    /// Unpacks an extension from the buffer, returning the deserialized value and the amount of
+    /// read bytes.
+    ///
+    /// The payload is borrowed from the buffer.
+    pub fn unpack(mut buf: &'a [u8]) -> Result<(usize, Self), Error> {
+        let (n, type_id, len) = take_ext_header(&mut buf)?;
+        let data = take_buffer(&mut buf, len)?;
+        Ok((n + len, Self { type_id, data }))
+    }
+}
+
+impl<'a> Packable for ExtensionRef<'a> {
+    #[allow(unreachable_code)]
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        if let Ok(n) = self.try_pack(buf) {
+            return n;
+        }
+        #[cfg(feature = "strict")]
+        panic!("strict serialization enabled; the buffer is too large");
+        0
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        let n = pack_ext_header(buf, self.type_id, self.data.len())?;
+        buf.put_slice(self.data);
+        Ok(n + self.data.len())
+    }
+
+    fn packed_len(&self) -> usize {
+        match ext_header_len(self.data.len()) {
+            0 => 0,
+            n => n + self.data.len(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Extension, Unpackable};
+    use core::time::Duration;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn extension_ref_bytes(t: i8, b: Vec<u8>) {
+            let x = ExtensionRef::new(t, &b);
+            let mut bytes = vec![];
+            let n = x.pack(&mut bytes);
+            assert_eq!(n, bytes.len());
+            assert_eq!(n, x.packed_len());
+            let (o, y) = ExtensionRef::unpack(&bytes).unwrap();
+            assert_eq!(n, o);
+            assert_eq!(x, y);
+
+            if t != Extension::TIMESTAMP {
+                let (_, z) = Extension::unpack(&bytes).unwrap();
+                assert_eq!(z, Extension::Ext(t, b.clone()));
+            }
+        }
+
+        #[test]
+        fn extension_ref_timestamp(secs in 0..=i64::MAX as u64, nanos in 0..1_000_000_000u32) {
+            let x = Extension::Timestamp(Duration::new(secs, nanos));
+            let mut bytes = vec![];
+            let n = x.pack(&mut bytes);
+            let (o, y) = ExtensionRef::unpack(&bytes).unwrap();
+            assert_eq!(n, o);
+            assert_eq!(y.type_id, ExtensionRef::TIMESTAMP);
+            assert!(matches!(y.data.len(), 4 | 8 | 12));
+        }
+    }
+
+    #[test]
+    fn extension_ref_errors() {
+        assert_eq!(ExtensionRef::unpack(&[]), Err(Error::BufferTooShort));
+        assert_eq!(ExtensionRef::unpack(&[0xc0]), Err(Error::InvalidExtension));
+        assert_eq!(
+            ExtensionRef::unpack(&[0xd6, 1, 0, 0]),
+            Err(Error::BufferTooShort)
+        );
+    }
+}
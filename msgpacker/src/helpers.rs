@@ -1,4 +1,5 @@
-use super::Error;
+use super::{Error, Format, PackBuf, PackError};
+use core::{mem::MaybeUninit, ptr};
 
 pub fn take_byte_iter<I>(mut bytes: I) -> Result<u8, Error>
 where
@@ -36,15 +37,123 @@ pub fn take_buffer<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error
     Ok(l)
 }
 
-pub fn take_num_iter<I, V, const N: usize>(bytes: I, f: fn([u8; N]) -> V) -> Result<V, Error>
+pub fn take_num_iter<I, V, const N: usize>(mut bytes: I, f: fn([u8; N]) -> V) -> Result<V, Error>
 where
     I: Iterator<Item = u8>,
 {
-    bytes
-        .array_chunks()
-        .next()
-        .ok_or(Error::BufferTooShort)
-        .map(f)
+    let mut val = [0u8; N];
+    for b in val.iter_mut() {
+        *b = bytes.next().ok_or(Error::BufferTooShort)?;
+    }
+    Ok(f(val))
+}
+
+/// A sink that discards the bytes, counting how many were written.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ByteCounter {
+    count: usize,
+}
+
+impl ByteCounter {
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl PackBuf for ByteCounter {
+    fn put_u8(&mut self, _b: u8) {
+        self.count += 1;
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        self.count += s.len();
+    }
+}
+
+/// Packs the header of an extension of type `t` with a payload of `len` bytes.
+pub fn pack_ext_header<T>(buf: &mut T, t: i8, len: usize) -> Result<usize, PackError>
+where
+    T: PackBuf,
+{
+    match len {
+        1 => buf.put_slice(&[Format::FIXEXT1, t as u8]),
+        2 => buf.put_slice(&[Format::FIXEXT2, t as u8]),
+        4 => buf.put_slice(&[Format::FIXEXT4, t as u8]),
+        8 => buf.put_slice(&[Format::FIXEXT8, t as u8]),
+        16 => buf.put_slice(&[Format::FIXEXT16, t as u8]),
+        _ if len <= u8::MAX as usize => {
+            buf.put_slice(&[Format::EXT8, len as u8, t as u8]);
+            return Ok(3);
+        }
+        _ if len <= u16::MAX as usize => {
+            buf.put_u8(Format::EXT16);
+            buf.put_slice(&(len as u16).to_be_bytes());
+            buf.put_u8(t as u8);
+            return Ok(4);
+        }
+        _ if len <= u32::MAX as usize => {
+            buf.put_u8(Format::EXT32);
+            buf.put_slice(&(len as u32).to_be_bytes());
+            buf.put_u8(t as u8);
+            return Ok(6);
+        }
+        _ => return Err(PackError::LengthOverflow),
+    }
+    Ok(2)
+}
+
+/// Returns the length of the header [pack_ext_header] will write, or `0` if the payload is too
+/// large to be represented by the protocol.
+pub fn ext_header_len(len: usize) -> usize {
+    match len {
+        1 | 2 | 4 | 8 | 16 => 2,
+        _ if len <= u8::MAX as usize => 3,
+        _ if len <= u16::MAX as usize => 4,
+        _ if len <= u32::MAX as usize => 6,
+        _ => 0,
+    }
+}
+
+/// Takes the header of an extension, returning its length, type and payload length.
+pub fn take_ext_header(buf: &mut &[u8]) -> Result<(usize, i8, usize), Error> {
+    let (n, len) = match take_byte(buf)? {
+        Format::FIXEXT1 => (2, 1),
+        Format::FIXEXT2 => (2, 2),
+        Format::FIXEXT4 => (2, 4),
+        Format::FIXEXT8 => (2, 8),
+        Format::FIXEXT16 => (2, 16),
+        Format::EXT8 => (3, take_byte(buf)? as usize),
+        Format::EXT16 => (4, take_num(buf, u16::from_be_bytes)? as usize),
+        Format::EXT32 => (6, take_num(buf, u32::from_be_bytes)? as usize),
+        _ => return Err(Error::InvalidExtension),
+    };
+    Ok((n, take_byte(buf)? as i8, len))
+}
+
+/// Takes the header of an extension, returning its length, type and payload length.
+#[cfg(feature = "alloc")]
+pub fn take_ext_header_iter<I>(mut bytes: I) -> Result<(usize, i8, usize), Error>
+where
+    I: Iterator<Item = u8>,
+{
+    let (n, len) = match take_byte_iter(bytes.by_ref())? {
+        Format::FIXEXT1 => (2, 1),
+        Format::FIXEXT2 => (2, 2),
+        Format::FIXEXT4 => (2, 4),
+        Format::FIXEXT8 => (2, 8),
+        Format::FIXEXT16 => (2, 16),
+        Format::EXT8 => (3, take_byte_iter(bytes.by_ref())? as usize),
+        Format::EXT16 => (
+            4,
+            take_num_iter(bytes.by_ref(), u16::from_be_bytes)? as usize,
+        ),
+        Format::EXT32 => (
+            6,
+            take_num_iter(bytes.by_ref(), u32::from_be_bytes)? as usize,
+        ),
+        _ => return Err(Error::InvalidExtension),
+    };
+    Ok((n, take_byte_iter(bytes)? as i8, len))
 }
 
 #[cfg(feature = "alloc")]
@@ -58,3 +167,45 @@ where
     }
     Ok(v)
 }
+
+/// A fixed-size array that is initialized element by element.
+///
+/// If the guard is dropped before the array is complete, the already written elements are dropped
+/// so a failure in the middle of a deserialization will not leak them.
+pub struct ArrayGuard<X, const N: usize> {
+    array: [MaybeUninit<X>; N],
+    len: usize,
+}
+
+impl<X, const N: usize> ArrayGuard<X, N> {
+    pub const fn new() -> Self {
+        Self {
+            array: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    pub fn push(&mut self, x: X) {
+        self.array[self.len].write(x);
+        self.len += 1;
+    }
+
+    pub fn into_array(self) -> [X; N] {
+        assert_eq!(self.len, N, "the array is not fully initialized");
+        // Safety: all the N elements are initialized, and the guard is forgotten so they won't be
+        // dropped twice
+        let array =
+            unsafe { ptr::read(&self.array as *const [MaybeUninit<X>; N] as *const [X; N]) };
+        core::mem::forget(self);
+        array
+    }
+}
+
+impl<X, const N: usize> Drop for ArrayGuard<X, N> {
+    fn drop(&mut self) {
+        for x in &mut self.array[..self.len] {
+            // Safety: the first `len` elements are initialized
+            unsafe { x.assume_init_drop() };
+        }
+    }
+}
@@ -1,65 +1,171 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![feature(
-    iter_array_chunks,
-    maybe_uninit_array_assume_init,
-    maybe_uninit_uninit_array
-)]
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+mod ext;
 #[cfg(feature = "alloc")]
 mod extension;
+#[cfg(feature = "alloc")]
+mod timestamp;
+#[cfg(feature = "alloc")]
+mod typed_array;
 
+mod buf;
+mod canonical;
 mod error;
+mod extension_ref;
 mod format;
 mod helpers;
 mod pack;
+mod tagged_option;
 mod unpack;
 
-pub use error::Error;
+pub use buf::{PackBuf, PackExtend};
+pub use canonical::Canonical;
+pub use error::{Error, PackError};
+pub use extension_ref::ExtensionRef;
 use format::Format;
-pub use pack::{pack_array, pack_map};
+pub use pack::{
+    pack_array, pack_f32_slice, pack_f64_slice, pack_i16_slice, pack_i32_slice, pack_i64_slice,
+    pack_into_slice, pack_map, pack_u16_slice, pack_u32_slice, pack_u64_slice, packed_array_len,
+    packed_map_len, try_pack_array, try_pack_map,
+};
+pub use tagged_option::TaggedOption;
 pub use unpack::{unpack_array, unpack_array_iter, unpack_map, unpack_map_iter};
 
+#[cfg(feature = "alloc")]
+pub use ext::{
+    pack_ext, packed_ext_len, try_pack_ext, unpack_ext, unpack_ext_iter, Ext, ExtRegistry,
+    MsgPackExt,
+};
 #[cfg(feature = "alloc")]
 pub use extension::Extension;
+#[cfg(feature = "alloc")]
+pub use pack::{pack_array_sorted, pack_map_sorted, try_pack_array_sorted, try_pack_map_sorted};
+#[cfg(feature = "alloc")]
+pub use typed_array::{TypedArray, TypedArrayElement};
+#[cfg(feature = "alloc")]
+pub use unpack::{
+    unpack_f32_vec, unpack_f64_vec, unpack_i16_vec, unpack_i32_vec, unpack_i64_vec,
+    unpack_map_unique, unpack_map_unique_iter, unpack_u16_vec, unpack_u32_vec, unpack_u64_vec,
+};
+
+#[cfg(feature = "bytes")]
+pub use buf::PackBufMut;
+#[cfg(feature = "bytes")]
+pub use unpack::{unpack_bytes_shared, unpack_str_shared};
 
 #[cfg(feature = "derive")]
 pub use msgpacker_derive::MsgPacker;
 
 /// A packable type.
 pub trait Packable {
-    /// Pack a value into the extendable buffer, returning the amount of written bytes.
+    /// Pack a value into the buffer, returning the amount of written bytes.
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>;
+        T: PackBuf;
+
+    /// Pack a value into the buffer, returning the amount of written bytes.
+    ///
+    /// Unlike [Packable::pack], this will fail with [PackError::LengthOverflow] if a buffer,
+    /// string or collection is too large to be represented by the protocol, instead of panicking
+    /// under the `strict` feature or silently skipping it. The buffer might contain a partially
+    /// packed value in that case.
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        Ok(self.pack(buf))
+    }
+
+    /// Returns the amount of bytes [Packable::pack] will write for this value.
+    ///
+    /// The default implementation packs the value into a counting sink. Implementors should
+    /// override it whenever the size can be computed without serializing the value.
+    fn packed_len(&self) -> usize {
+        let mut counter = helpers::ByteCounter::default();
+        self.pack(&mut counter);
+        counter.count()
+    }
+
+    /// Packs a slice of values, as an array by default.
+    ///
+    /// Used by the implementations of slices and vectors so bytes can be packed as binary.
+    #[doc(hidden)]
+    fn pack_slice<T>(slice: &[Self], buf: &mut T) -> usize
+    where
+        Self: Sized,
+        T: PackBuf,
+    {
+        pack_array(buf, slice)
+    }
+
+    #[doc(hidden)]
+    fn try_pack_slice<T>(slice: &[Self], buf: &mut T) -> Result<usize, PackError>
+    where
+        Self: Sized,
+        T: PackBuf,
+    {
+        try_pack_array(buf, slice)
+    }
+
+    #[doc(hidden)]
+    fn packed_slice_len(slice: &[Self]) -> usize
+    where
+        Self: Sized,
+    {
+        packed_array_len(slice)
+    }
 }
 
-impl<'a, X> Packable for &'a X
+impl<X> Packable for &X
 where
-    X: Packable,
+    X: Packable + ?Sized,
 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         X::pack(self, buf)
     }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        X::try_pack(self, buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        X::packed_len(self)
+    }
 }
 
-impl<'a, X> Packable for &'a mut X
+impl<X> Packable for &mut X
 where
-    X: Packable,
+    X: Packable + ?Sized,
 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         X::pack(self, buf)
     }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        X::try_pack(self, buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        X::packed_len(self)
+    }
 }
 
 /// An unpackable type.
@@ -86,15 +192,39 @@ pub trait Unpackable: Sized {
     fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
     where
         I: IntoIterator<Item = u8>;
+
+    /// Unpacks a vector of values, from an array by default.
+    ///
+    /// Used by the implementation of vectors so bytes can be unpacked from binary.
+    #[doc(hidden)]
+    #[cfg(feature = "alloc")]
+    fn unpack_vec(buf: &[u8]) -> Result<(usize, alloc::vec::Vec<Self>), Self::Error> {
+        unpack_array(buf)
+    }
+
+    #[doc(hidden)]
+    #[cfg(feature = "alloc")]
+    fn unpack_vec_iter<I>(bytes: I) -> Result<(usize, alloc::vec::Vec<Self>), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        unpack_array_iter(bytes)
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub mod __private {
+    pub use super::ext::{PackPayload, UnpackPayload};
 }
 
 /// Required types for the library.
 pub mod prelude {
-    pub use super::{Error, Packable, Unpackable};
+    pub use super::{Error, ExtensionRef, PackBuf, PackError, Packable, Unpackable};
 
     #[cfg(feature = "derive")]
     pub use super::MsgPacker;
 
     #[cfg(feature = "alloc")]
-    pub use super::Extension;
+    pub use super::{Extension, MsgPackExt};
 }
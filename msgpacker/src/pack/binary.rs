@@ -1,28 +1,51 @@
-use super::{Format, Packable};
-use core::iter;
+use super::{Format, PackBuf, PackError, Packable};
 
-impl Packable for [u8] {
-    #[allow(unreachable_code)]
-    fn pack<T>(&self, buf: &mut T) -> usize
-    where
-        T: Extend<u8>,
-    {
-        let n = if self.len() <= u8::MAX as usize {
-            buf.extend(iter::once(Format::BIN8).chain(iter::once(self.len() as u8)));
-            2
-        } else if self.len() <= u16::MAX as usize {
-            buf.extend(iter::once(Format::BIN16).chain((self.len() as u16).to_be_bytes()));
-            3
-        } else if self.len() <= u32::MAX as usize {
-            buf.extend(iter::once(Format::BIN32).chain((self.len() as u32).to_be_bytes()));
-            5
-        } else {
-            #[cfg(feature = "strict")]
-            panic!("strict serialization enabled; the buffer is too large");
-            return 0;
-        };
-        buf.extend(self.iter().copied());
-        n + self.len()
+/// Packs the bytes as binary; used by the slices and vectors of `u8`.
+#[allow(unreachable_code)]
+pub(super) fn pack_bytes<T>(bytes: &[u8], buf: &mut T) -> usize
+where
+    T: PackBuf,
+{
+    let n = if bytes.len() <= u8::MAX as usize {
+        buf.put_slice(&[Format::BIN8, bytes.len() as u8]);
+        2
+    } else if bytes.len() <= u16::MAX as usize {
+        buf.put_u8(Format::BIN16);
+        buf.put_slice(&(bytes.len() as u16).to_be_bytes());
+        3
+    } else if bytes.len() <= u32::MAX as usize {
+        buf.put_u8(Format::BIN32);
+        buf.put_slice(&(bytes.len() as u32).to_be_bytes());
+        5
+    } else {
+        #[cfg(feature = "strict")]
+        panic!("strict serialization enabled; the buffer is too large");
+        return 0;
+    };
+    buf.reserve(bytes.len());
+    buf.put_slice(bytes);
+    n + bytes.len()
+}
+
+pub(super) fn try_pack_bytes<T>(bytes: &[u8], buf: &mut T) -> Result<usize, PackError>
+where
+    T: PackBuf,
+{
+    if bytes.len() > u32::MAX as usize {
+        return Err(PackError::LengthOverflow);
+    }
+    Ok(pack_bytes(bytes, buf))
+}
+
+pub(super) fn packed_bytes_len(bytes: &[u8]) -> usize {
+    if bytes.len() <= u8::MAX as usize {
+        2 + bytes.len()
+    } else if bytes.len() <= u16::MAX as usize {
+        3 + bytes.len()
+    } else if bytes.len() <= u32::MAX as usize {
+        5 + bytes.len()
+    } else {
+        0
     }
 }
 
@@ -30,50 +53,179 @@ impl Packable for [u8] {
 impl Packable for str {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         let n = if self.len() <= 31 {
-            buf.extend(iter::once((self.len() as u8 & 0x1f) | 0xa0));
+            buf.put_u8((self.len() as u8 & 0x1f) | 0xa0);
             1
         } else if self.len() <= u8::MAX as usize {
-            buf.extend(iter::once(Format::STR8).chain(iter::once(self.len() as u8)));
+            buf.put_slice(&[Format::STR8, self.len() as u8]);
             2
         } else if self.len() <= u16::MAX as usize {
-            buf.extend(iter::once(Format::STR16).chain((self.len() as u16).to_be_bytes()));
+            buf.put_u8(Format::STR16);
+            buf.put_slice(&(self.len() as u16).to_be_bytes());
             3
         } else if self.len() <= u32::MAX as usize {
-            buf.extend(iter::once(Format::STR32).chain((self.len() as u32).to_be_bytes()));
+            buf.put_u8(Format::STR32);
+            buf.put_slice(&(self.len() as u32).to_be_bytes());
             5
         } else {
             #[cfg(feature = "strict")]
             panic!("strict serialization enabled; the buffer is too large");
             return 0;
         };
-        buf.extend(self.as_bytes().iter().copied());
+        buf.reserve(self.len());
+        buf.put_slice(self.as_bytes());
         n + self.len()
     }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        if self.len() > u32::MAX as usize {
+            return Err(PackError::LengthOverflow);
+        }
+        Ok(self.pack(buf))
+    }
+
+    fn packed_len(&self) -> usize {
+        if self.len() <= 31 {
+            1 + self.len()
+        } else if self.len() <= u8::MAX as usize {
+            2 + self.len()
+        } else if self.len() <= u16::MAX as usize {
+            3 + self.len()
+        } else if self.len() <= u32::MAX as usize {
+            5 + self.len()
+        } else {
+            0
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
 mod alloc {
     use super::*;
-    use ::alloc::{string::String, vec::Vec};
+    use ::alloc::string::String;
 
-    impl Packable for Vec<u8> {
+    impl Packable for String {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
-            self.as_slice().pack(buf)
+            self.as_str().pack(buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            self.as_str().try_pack(buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            self.as_str().packed_len()
         }
     }
+}
 
-    impl Packable for String {
+#[cfg(feature = "bytes")]
+mod shared {
+    use super::*;
+    use ::bytes::{Bytes, BytesMut};
+
+    impl Packable for Bytes {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
-            self.as_str().pack(buf)
+            pack_bytes(self, buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_bytes(self, buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_bytes_len(self)
+        }
+    }
+
+    impl Packable for BytesMut {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_bytes(self, buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_bytes(self, buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_bytes_len(self)
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid {
+    use super::*;
+    use ::uuid::Uuid;
+
+    impl Packable for Uuid {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_bytes(self.as_bytes(), buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_bytes(self.as_bytes(), buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_bytes_len(self.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    use super::*;
+    use ::alloc::string::ToString;
+    use ::rust_decimal::Decimal;
+
+    // Packed as its exact string representation, as there is no decimal format in the protocol.
+    impl Packable for Decimal {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            self.to_string().pack(buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            self.to_string().try_pack(buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            self.to_string().packed_len()
         }
     }
 }
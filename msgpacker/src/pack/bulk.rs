@@ -0,0 +1,74 @@
+use super::{collections::pack_array_header, Format, PackBuf};
+
+/// Amount of elements encoded on the stack before they are written to the buffer.
+const CHUNK: usize = 64;
+
+/// Packs a slice as an array of fixed-width elements, each prefixed by `format`.
+#[allow(unreachable_code)]
+fn pack_slice<T, X, const N: usize>(
+    buf: &mut T,
+    values: &[X],
+    format: u8,
+    to_be_bytes: fn(X) -> [u8; N],
+) -> usize
+where
+    T: PackBuf,
+    X: Copy,
+{
+    let n = match pack_array_header(buf, values.len()) {
+        Ok(n) => n,
+        Err(_) => {
+            #[cfg(feature = "strict")]
+            panic!("strict serialization enabled; the buffer is too large");
+            return 0;
+        }
+    };
+    let len = values.len() * (N + 1);
+    buf.reserve(len);
+    let mut chunk = [0u8; CHUNK * 9];
+    for values in values.chunks(CHUNK) {
+        let chunk = &mut chunk[..values.len() * (N + 1)];
+        for (c, v) in chunk.chunks_exact_mut(N + 1).zip(values) {
+            c[0] = format;
+            c[1..].copy_from_slice(&to_be_bytes(*v));
+        }
+        buf.put_slice(chunk);
+    }
+    n + len
+}
+
+macro_rules! pack_slice {
+    (int $f:ident, $t:ty, $format:expr, $n:expr) => {
+        pack_slice!(
+            #[doc = ""]
+            #[doc = "The output is not canonical: values that fit a smaller format, such as a fixint, are"]
+            #[doc = "rejected by [crate::Canonical]. Use [crate::pack_array] where a canonical encoding"]
+            #[doc = "is required."]
+            $f, $t, $format, $n
+        );
+    };
+    ($(#[$doc:meta])* $f:ident, $t:ty, $format:expr, $n:expr) => {
+        #[doc = concat!(
+            "Packs a slice of `", stringify!($t), "` as an array, returning the amount of written bytes.\n\n",
+            "Every element is encoded with the fixed-width `", stringify!($format), "` format, so the ",
+            "slice is serialized with a single pass of byte-swapping instead of one branch per element. ",
+            "The output is a regular array that can be unpacked by any decoder."
+        )]
+        $(#[$doc])*
+        pub fn $f<T>(buf: &mut T, values: &[$t]) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_slice::<T, $t, $n>(buf, values, $format, <$t>::to_be_bytes)
+        }
+    };
+}
+
+pack_slice!(int pack_u16_slice, u16, Format::UINT16, 2);
+pack_slice!(int pack_u32_slice, u32, Format::UINT32, 4);
+pack_slice!(int pack_u64_slice, u64, Format::UINT64, 8);
+pack_slice!(int pack_i16_slice, i16, Format::INT16, 2);
+pack_slice!(int pack_i32_slice, i32, Format::INT32, 4);
+pack_slice!(int pack_i64_slice, i64, Format::INT64, 8);
+pack_slice!(pack_f32_slice, f32, Format::FLOAT32, 4);
+pack_slice!(pack_f64_slice, f64, Format::FLOAT64, 8);
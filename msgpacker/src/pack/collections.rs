@@ -1,39 +1,188 @@
-use super::{Format, Packable};
-use core::{borrow::Borrow, iter};
+use super::{Format, PackBuf, PackError, Packable};
+use core::borrow::Borrow;
 
-/// Packs an array into the extendable buffer, returning the amount of written bytes.
+pub(super) fn pack_array_header<T>(buf: &mut T, len: usize) -> Result<usize, PackError>
+where
+    T: PackBuf,
+{
+    if len <= 15 {
+        buf.put_u8(((len & 0x0f) as u8) | 0x90);
+        Ok(1)
+    } else if len <= u16::MAX as usize {
+        buf.put_u8(Format::ARRAY16);
+        buf.put_slice(&(len as u16).to_be_bytes());
+        Ok(3)
+    } else if len <= u32::MAX as usize {
+        buf.put_u8(Format::ARRAY32);
+        buf.put_slice(&(len as u32).to_be_bytes());
+        Ok(5)
+    } else {
+        Err(PackError::LengthOverflow)
+    }
+}
+
+fn pack_map_header<T>(buf: &mut T, len: usize) -> Result<usize, PackError>
+where
+    T: PackBuf,
+{
+    if len <= 15 {
+        buf.put_u8(((len & 0x0f) as u8) | 0x80);
+        Ok(1)
+    } else if len <= u16::MAX as usize {
+        buf.put_u8(Format::MAP16);
+        buf.put_slice(&(len as u16).to_be_bytes());
+        Ok(3)
+    } else if len <= u32::MAX as usize {
+        buf.put_u8(Format::MAP32);
+        buf.put_slice(&(len as u32).to_be_bytes());
+        Ok(5)
+    } else {
+        Err(PackError::LengthOverflow)
+    }
+}
+
+/// Returns the length of an array or map header; `None` if the length overflows the protocol.
+fn header_len(len: usize) -> Option<usize> {
+    if len <= 15 {
+        Some(1)
+    } else if len <= u16::MAX as usize {
+        Some(3)
+    } else if len <= u32::MAX as usize {
+        Some(5)
+    } else {
+        None
+    }
+}
+
+/// Packs an array into the buffer, returning the amount of written bytes.
+///
+/// The header and at least one byte per element are reserved up front.
 #[allow(unreachable_code)]
 pub fn pack_array<T, A, I, V>(buf: &mut T, iter: A) -> usize
 where
-    T: Extend<u8>,
+    T: PackBuf,
     A: IntoIterator<IntoIter = I>,
     I: Iterator<Item = V> + ExactSizeIterator,
     V: Packable,
 {
     let values = iter.into_iter();
-    let len = values.len();
-    let n = if len <= 15 {
-        buf.extend(iter::once(((len & 0x0f) as u8) | 0x90));
-        1
-    } else if len <= u16::MAX as usize {
-        buf.extend(iter::once(Format::ARRAY16).chain((len as u16).to_be_bytes()));
-        3
-    } else if len <= u32::MAX as usize {
-        buf.extend(iter::once(Format::ARRAY32).chain((len as u32).to_be_bytes()));
-        5
-    } else {
-        #[cfg(feature = "strict")]
-        panic!("strict serialization enabled; the buffer is too large");
-        return 0;
+    if let Some(n) = header_len(values.len()) {
+        buf.reserve(n + values.len());
+    }
+    let n = match pack_array_header(buf, values.len()) {
+        Ok(n) => n,
+        Err(_) => {
+            #[cfg(feature = "strict")]
+            panic!("strict serialization enabled; the buffer is too large");
+            return 0;
+        }
     };
     n + values.map(|v| v.pack(buf)).sum::<usize>()
 }
 
-/// Packs a map into the extendable buffer, returning the amount of written bytes.
+/// Packs an array into the buffer, returning the amount of written bytes.
+///
+/// The header and at least one byte per element are reserved up front.
+///
+/// Fails with [PackError::LengthOverflow] if the array or any of its elements is too large to be
+/// represented by the protocol. The buffer might contain a partially packed array in that case.
+pub fn try_pack_array<T, A, I, V>(buf: &mut T, iter: A) -> Result<usize, PackError>
+where
+    T: PackBuf,
+    A: IntoIterator<IntoIter = I>,
+    I: Iterator<Item = V> + ExactSizeIterator,
+    V: Packable,
+{
+    let values = iter.into_iter();
+    if let Some(n) = header_len(values.len()) {
+        buf.reserve(n + values.len());
+    }
+    let mut n = pack_array_header(buf, values.len())?;
+    for v in values {
+        n += v.try_pack(buf)?;
+    }
+    Ok(n)
+}
+
+/// Packs a map into the buffer, returning the amount of written bytes.
+///
+/// The header and at least one byte per key and value are reserved up front.
 #[allow(unreachable_code)]
 pub fn pack_map<T, A, I, B, K, V>(buf: &mut T, iter: A) -> usize
 where
-    T: Extend<u8>,
+    T: PackBuf,
+    A: IntoIterator<IntoIter = I>,
+    B: Borrow<(K, V)>,
+    I: Iterator<Item = B> + ExactSizeIterator,
+    K: Packable,
+    V: Packable,
+{
+    let map = iter.into_iter();
+    if let Some(n) = header_len(map.len()) {
+        buf.reserve(n + 2 * map.len());
+    }
+    let n = match pack_map_header(buf, map.len()) {
+        Ok(n) => n,
+        Err(_) => {
+            #[cfg(feature = "strict")]
+            panic!("strict serialization enabled; the buffer is too large");
+            return 0;
+        }
+    };
+    n + map
+        .map(|b| {
+            let (k, v) = b.borrow();
+            k.pack(buf) + v.pack(buf)
+        })
+        .sum::<usize>()
+}
+
+/// Packs a map into the buffer, returning the amount of written bytes.
+///
+/// The header and at least one byte per key and value are reserved up front.
+///
+/// Fails with [PackError::LengthOverflow] if the map or any of its entries is too large to be
+/// represented by the protocol. The buffer might contain a partially packed map in that case.
+pub fn try_pack_map<T, A, I, B, K, V>(buf: &mut T, iter: A) -> Result<usize, PackError>
+where
+    T: PackBuf,
+    A: IntoIterator<IntoIter = I>,
+    B: Borrow<(K, V)>,
+    I: Iterator<Item = B> + ExactSizeIterator,
+    K: Packable,
+    V: Packable,
+{
+    let map = iter.into_iter();
+    if let Some(n) = header_len(map.len()) {
+        buf.reserve(n + 2 * map.len());
+    }
+    let mut n = pack_map_header(buf, map.len())?;
+    for b in map {
+        let (k, v) = b.borrow();
+        n += k.try_pack(buf)?;
+        n += v.try_pack(buf)?;
+    }
+    Ok(n)
+}
+
+/// Returns the amount of bytes [pack_array] will write for the provided iterator.
+pub fn packed_array_len<A, I, V>(iter: A) -> usize
+where
+    A: IntoIterator<IntoIter = I>,
+    I: Iterator<Item = V> + ExactSizeIterator,
+    V: Packable,
+{
+    let values = iter.into_iter();
+    let len = values.len();
+    let Some(n) = header_len(len) else {
+        return 0;
+    };
+    n + values.map(|v| v.packed_len()).sum::<usize>()
+}
+
+/// Returns the amount of bytes [pack_map] will write for the provided iterator.
+pub fn packed_map_len<A, I, B, K, V>(iter: A) -> usize
+where
     A: IntoIterator<IntoIter = I>,
     B: Borrow<(K, V)>,
     I: Iterator<Item = B> + ExactSizeIterator,
@@ -42,32 +191,225 @@ where
 {
     let map = iter.into_iter();
     let len = map.len();
-    let n = if len <= 15 {
-        buf.extend(iter::once(((len & 0x0f) as u8) | 0x80));
-        1
-    } else if len <= u16::MAX as usize {
-        buf.extend(iter::once(Format::MAP16).chain((len as u16).to_be_bytes()));
-        3
-    } else if len <= u32::MAX as usize {
-        buf.extend(iter::once(Format::MAP32).chain((len as u32).to_be_bytes()));
-        5
-    } else {
-        #[cfg(feature = "strict")]
-        panic!("strict serialization enabled; the buffer is too large");
+    let Some(n) = header_len(len) else {
         return 0;
     };
     n + map
         .map(|b| {
             let (k, v) = b.borrow();
-            k.pack(buf) + v.pack(buf)
+            k.packed_len() + v.packed_len()
         })
         .sum::<usize>()
 }
 
+impl<X> Packable for [X]
+where
+    X: Packable,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        X::pack_slice(self, buf)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        X::try_pack_slice(self, buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        X::packed_slice_len(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use alloc::{pack_array_sorted, pack_map_sorted, try_pack_array_sorted, try_pack_map_sorted};
+
 #[cfg(feature = "alloc")]
 mod alloc {
     use super::*;
     use ::alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+    use ::alloc::vec::Vec;
+    use core::convert::Infallible;
+    use core::ops::Range;
+
+    /// Packed entries of a collection, along with the ranges of their sort keys and contents.
+    ///
+    /// Only the entries themselves are sorted; collections nested in them are packed as usual.
+    struct Sorted {
+        bytes: Vec<u8>,
+        entries: Vec<(Range<usize>, Range<usize>)>,
+    }
+
+    impl Sorted {
+        /// Packs every entry via `f`, which returns the length of the sort key prefix, and sorts
+        /// the entries by their key bytes.
+        fn new<I, F, E>(entries: I, mut f: F) -> Result<Self, E>
+        where
+            I: Iterator,
+            F: FnMut(I::Item, &mut Vec<u8>) -> Result<usize, E>,
+        {
+            let mut bytes = Vec::new();
+            let mut ranges = Vec::with_capacity(entries.size_hint().0);
+            for e in entries {
+                let start = bytes.len();
+                let key = f(e, &mut bytes)?;
+                ranges.push((start..start + key, start..bytes.len()));
+            }
+            ranges.sort_by(|a, b| bytes[a.0.clone()].cmp(&bytes[b.0.clone()]));
+            Ok(Self {
+                bytes,
+                entries: ranges,
+            })
+        }
+
+        fn put<T>(self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            buf.reserve(self.bytes.len());
+            for (_, e) in self.entries {
+                buf.put_slice(&self.bytes[e]);
+            }
+            self.bytes.len()
+        }
+    }
+
+    /// Packs an array into the buffer with its elements sorted by their packed bytes, returning
+    /// the amount of written bytes.
+    ///
+    /// The output is deterministic regardless of the iteration order of the collection, which is
+    /// useful for unordered collections such as `HashSet`. Only the elements of this collection are
+    /// sorted; unordered collections nested in them are packed in their own iteration order.
+    #[allow(unreachable_code)]
+    pub fn pack_array_sorted<T, A, I, V>(buf: &mut T, iter: A) -> usize
+    where
+        T: PackBuf,
+        A: IntoIterator<IntoIter = I>,
+        I: Iterator<Item = V> + ExactSizeIterator,
+        V: Packable,
+    {
+        let values = iter.into_iter();
+        let n = match pack_array_header(buf, values.len()) {
+            Ok(n) => n,
+            Err(_) => {
+                #[cfg(feature = "strict")]
+                panic!("strict serialization enabled; the buffer is too large");
+                return 0;
+            }
+        };
+        let Ok(sorted) = Sorted::new::<_, _, Infallible>(values, |v, b| Ok(v.pack(b)));
+        n + sorted.put(buf)
+    }
+
+    /// Packs an array into the buffer with its elements sorted by their packed bytes, returning
+    /// the amount of written bytes.
+    ///
+    /// Fails with [PackError::LengthOverflow] if the array or any of its elements is too large to
+    /// be represented by the protocol. Nothing is written to the buffer in that case.
+    pub fn try_pack_array_sorted<T, A, I, V>(buf: &mut T, iter: A) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+        A: IntoIterator<IntoIter = I>,
+        I: Iterator<Item = V> + ExactSizeIterator,
+        V: Packable,
+    {
+        let values = iter.into_iter();
+        let len = values.len();
+        if len > u32::MAX as usize {
+            return Err(PackError::LengthOverflow);
+        }
+        let sorted = Sorted::new(values, |v, b| v.try_pack(b))?;
+        Ok(pack_array_header(buf, len)? + sorted.put(buf))
+    }
+
+    /// Packs a map into the buffer with its entries sorted by their packed keys, returning the
+    /// amount of written bytes.
+    ///
+    /// The output is deterministic regardless of the iteration order of the map, which is useful
+    /// for unordered maps such as `HashMap`. Only the entries of this map are sorted; unordered
+    /// collections nested in its keys or values are packed in their own iteration order.
+    #[allow(unreachable_code)]
+    pub fn pack_map_sorted<T, A, I, B, K, V>(buf: &mut T, iter: A) -> usize
+    where
+        T: PackBuf,
+        A: IntoIterator<IntoIter = I>,
+        B: Borrow<(K, V)>,
+        I: Iterator<Item = B> + ExactSizeIterator,
+        K: Packable,
+        V: Packable,
+    {
+        let map = iter.into_iter();
+        let n = match pack_map_header(buf, map.len()) {
+            Ok(n) => n,
+            Err(_) => {
+                #[cfg(feature = "strict")]
+                panic!("strict serialization enabled; the buffer is too large");
+                return 0;
+            }
+        };
+        let Ok(sorted) = Sorted::new::<_, _, Infallible>(map, |e, b| {
+            let (k, v) = e.borrow();
+            let n = k.pack(b);
+            v.pack(b);
+            Ok(n)
+        });
+        n + sorted.put(buf)
+    }
+
+    /// Packs a map into the buffer with its entries sorted by their packed keys, returning the
+    /// amount of written bytes.
+    ///
+    /// Fails with [PackError::LengthOverflow] if the map or any of its entries is too large to be
+    /// represented by the protocol. Nothing is written to the buffer in that case.
+    pub fn try_pack_map_sorted<T, A, I, B, K, V>(buf: &mut T, iter: A) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+        A: IntoIterator<IntoIter = I>,
+        B: Borrow<(K, V)>,
+        I: Iterator<Item = B> + ExactSizeIterator,
+        K: Packable,
+        V: Packable,
+    {
+        let map = iter.into_iter();
+        let len = map.len();
+        if len > u32::MAX as usize {
+            return Err(PackError::LengthOverflow);
+        }
+        let sorted = Sorted::new(map, |e, b| {
+            let (k, v) = e.borrow();
+            let n = k.try_pack(b)?;
+            v.try_pack(b)?;
+            Ok(n)
+        })?;
+        Ok(pack_map_header(buf, len)? + sorted.put(buf))
+    }
+
+    impl<X> Packable for Vec<X>
+    where
+        X: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            X::pack_slice(self, buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            X::try_pack_slice(self, buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            X::packed_slice_len(self)
+        }
+    }
 
     impl<X> Packable for BTreeSet<X>
     where
@@ -75,10 +417,21 @@ mod alloc {
     {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
             pack_array(buf, self)
         }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
     }
 
     impl<X> Packable for BinaryHeap<X>
@@ -87,10 +440,21 @@ mod alloc {
     {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
             pack_array(buf, self)
         }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
     }
 
     impl<X> Packable for LinkedList<X>
@@ -99,10 +463,21 @@ mod alloc {
     {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
             pack_array(buf, self)
         }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
     }
 
     impl<X> Packable for VecDeque<X>
@@ -111,10 +486,21 @@ mod alloc {
     {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
             pack_array(buf, self)
         }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
     }
 
     impl<K, V> Packable for BTreeMap<K, V>
@@ -124,10 +510,21 @@ mod alloc {
     {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
             pack_map(buf, self)
         }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_map(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_map_len(self)
+        }
     }
 }
 
@@ -142,10 +539,21 @@ mod std {
     {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
             pack_array(buf, self)
         }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
     }
 
     impl<K, V> Packable for HashMap<K, V>
@@ -155,9 +563,351 @@ mod std {
     {
         fn pack<T>(&self, buf: &mut T) -> usize
         where
-            T: Extend<u8>,
+            T: PackBuf,
         {
             pack_map(buf, self)
         }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_map(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_map_len(self)
+        }
+    }
+}
+
+#[cfg(feature = "indexmap")]
+mod indexmap {
+    use super::*;
+    use ::indexmap::{IndexMap, IndexSet};
+
+    impl<X, S> Packable for IndexSet<X, S>
+    where
+        X: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_array(buf, self)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
+    }
+
+    impl<K, V, S> Packable for IndexMap<K, V, S>
+    where
+        K: Packable,
+        V: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_map(buf, self)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_map(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_map_len(self)
+        }
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+mod hashbrown {
+    use super::*;
+    use ::hashbrown::{HashMap, HashSet};
+
+    impl<X, S> Packable for HashSet<X, S>
+    where
+        X: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_array(buf, self)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
+    }
+
+    impl<K, V, S> Packable for HashMap<K, V, S>
+    where
+        K: Packable,
+        V: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_map(buf, self)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_map(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_map_len(self)
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec {
+    use super::*;
+    use ::smallvec::{Array, SmallVec};
+
+    // Packed as a `Vec`, so a vector of bytes is a binary.
+    impl<A> Packable for SmallVec<A>
+    where
+        A: Array,
+        A::Item: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            A::Item::pack_slice(self, buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            A::Item::try_pack_slice(self, buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            A::Item::packed_slice_len(self)
+        }
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec {
+    use super::*;
+    use ::arrayvec::{ArrayString, ArrayVec};
+
+    // Always packed as an array; unlike a `Vec`, a vector of bytes isn't packed as a binary.
+    impl<X, const CAP: usize> Packable for ArrayVec<X, CAP>
+    where
+        X: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_array(buf, self)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
+    }
+
+    impl<const CAP: usize> Packable for ArrayString<CAP> {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            self.as_str().pack(buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            self.as_str().try_pack(buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            self.as_str().packed_len()
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless {
+    use super::*;
+    use ::heapless::{IndexMap, IndexSet, LinearMap, String, Vec};
+
+    /// An iterator of known length; the iterators of the `heapless` maps and sets don't implement
+    /// [ExactSizeIterator].
+    struct Exact<I>(I, usize);
+
+    impl<I> Iterator for Exact<I>
+    where
+        I: Iterator,
+    {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let item = self.0.next()?;
+            self.1 = self.1.saturating_sub(1);
+            Some(item)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.1, Some(self.1))
+        }
+    }
+
+    impl<I> ExactSizeIterator for Exact<I> where I: Iterator {}
+
+    // Always packed as an array; unlike a `Vec`, a vector of bytes isn't packed as a binary.
+    impl<X, const N: usize> Packable for Vec<X, N>
+    where
+        X: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_array(buf, self)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, self)
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(self)
+        }
+    }
+
+    impl<const N: usize> Packable for String<N> {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            self.as_str().pack(buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            self.as_str().try_pack(buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            self.as_str().packed_len()
+        }
+    }
+
+    impl<X, S, const N: usize> Packable for IndexSet<X, S, N>
+    where
+        X: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_array(buf, Exact(self.iter(), self.len()))
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_array(buf, Exact(self.iter(), self.len()))
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_array_len(Exact(self.iter(), self.len()))
+        }
+    }
+
+    impl<K, V, S, const N: usize> Packable for IndexMap<K, V, S, N>
+    where
+        K: Packable,
+        V: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_map(buf, Exact(self.iter(), self.len()))
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_map(buf, Exact(self.iter(), self.len()))
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_map_len(Exact(self.iter(), self.len()))
+        }
+    }
+
+    impl<K, V, const N: usize> Packable for LinearMap<K, V, N>
+    where
+        K: Packable + Eq,
+        V: Packable,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_map(buf, Exact(self.iter(), self.len()))
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            try_pack_map(buf, Exact(self.iter(), self.len()))
+        }
+
+        fn packed_len(&self) -> usize {
+            packed_map_len(Exact(self.iter(), self.len()))
+        }
     }
 }
@@ -1,36 +1,103 @@
-use super::{Format, Packable};
-use core::{iter, marker::PhantomData};
+use super::{Format, PackBuf, PackError, Packable};
+use core::{cmp::Ordering, marker::PhantomData, time::Duration};
 
 impl Packable for () {
     fn pack<T>(&self, _buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         0
     }
+
+    fn packed_len(&self) -> usize {
+        0
+    }
 }
 
 impl<X> Packable for PhantomData<X> {
     fn pack<T>(&self, _buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         0
     }
+
+    fn packed_len(&self) -> usize {
+        0
+    }
 }
 
 impl Packable for bool {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self {
-            buf.extend(iter::once(Format::TRUE));
+            buf.put_u8(Format::TRUE);
         } else {
-            buf.extend(iter::once(Format::FALSE));
+            buf.put_u8(Format::FALSE);
         }
         1
     }
+
+    fn packed_len(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(target_has_atomic = "8")]
+impl Packable for core::sync::atomic::AtomicBool {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        self.load(core::sync::atomic::Ordering::Relaxed).pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        1
+    }
+}
+
+impl Packable for char {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        (*self as u32).pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        (*self as u32).packed_len()
+    }
+}
+
+impl Packable for Ordering {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        (*self as i8).pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        1
+    }
+}
+
+impl Packable for Duration {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        // fixed array of the seconds and nanoseconds
+        buf.put_u8(0x92);
+        1 + self.as_secs().pack(buf) + self.subsec_nanos().pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        1 + self.as_secs().packed_len() + self.subsec_nanos().packed_len()
+    }
 }
 
 impl<X> Packable for Option<X>
@@ -39,16 +106,69 @@ where
 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         match self {
             Some(t) => t.pack(buf),
             None => {
-                buf.extend(iter::once(Format::NIL));
+                buf.put_u8(Format::NIL);
                 1
             }
         }
     }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        match self {
+            Some(t) => t.try_pack(buf),
+            None => {
+                buf.put_u8(Format::NIL);
+                Ok(1)
+            }
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        match self {
+            Some(t) => t.packed_len(),
+            None => 1,
+        }
+    }
+}
+
+impl<X, E> Packable for Result<X, E>
+where
+    X: Packable,
+    E: Packable,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        match self {
+            Ok(x) => 0u32.pack(buf) + x.pack(buf),
+            Err(e) => 1u32.pack(buf) + e.pack(buf),
+        }
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        match self {
+            Ok(x) => Ok(0u32.pack(buf) + x.try_pack(buf)?),
+            Err(e) => Ok(1u32.pack(buf) + e.try_pack(buf)?),
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        match self {
+            Ok(x) => 1 + x.packed_len(),
+            Err(e) => 1 + e.packed_len(),
+        }
+    }
 }
 
 macro_rules! array {
@@ -59,10 +179,21 @@ macro_rules! array {
         {
             fn pack<T>(&self, buf: &mut T) -> usize
             where
-                T: Extend<u8>,
+                T: PackBuf,
             {
                 self.iter().map(|t| t.pack(buf)).sum()
             }
+
+            fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+            where
+                T: PackBuf,
+            {
+                self.iter().map(|t| t.try_pack(buf)).sum()
+            }
+
+            fn packed_len(&self) -> usize {
+                self.iter().map(|t| t.packed_len()).sum()
+            }
         }
     };
 }
@@ -141,12 +272,29 @@ macro_rules! tuple {
             #[allow(non_snake_case)]
             fn pack<TT>(&self, buf: &mut TT) -> usize
             where
-                TT: Extend<u8>,
+                TT: PackBuf,
             {
                 let ($(ref $name,)+) = *self;
 
                 0 $( + $name.pack(buf))+
             }
+
+            #[allow(non_snake_case)]
+            fn try_pack<TT>(&self, buf: &mut TT) -> Result<usize, PackError>
+            where
+                TT: PackBuf,
+            {
+                let ($(ref $name,)+) = *self;
+
+                Ok(0 $( + $name.try_pack(buf)?)+)
+            }
+
+            #[allow(non_snake_case)]
+            fn packed_len(&self) -> usize {
+                let ($(ref $name,)+) = *self;
+
+                0 $( + $name.packed_len())+
+            }
         }
     );
 }
@@ -1,12 +1,16 @@
-use super::{Format, Packable};
-use core::iter;
+use super::{Format, PackBuf, Packable};
 
 impl Packable for f32 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
-        buf.extend(iter::once(Format::FLOAT32).chain(self.to_be_bytes()));
+        buf.put_u8(Format::FLOAT32);
+        buf.put_slice(&self.to_be_bytes());
+        5
+    }
+
+    fn packed_len(&self) -> usize {
         5
     }
 }
@@ -14,9 +18,47 @@ impl Packable for f32 {
 impl Packable for f64 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
-        buf.extend(iter::once(Format::FLOAT64).chain(self.to_be_bytes()));
+        buf.put_u8(Format::FLOAT64);
+        buf.put_slice(&self.to_be_bytes());
         9
     }
+
+    fn packed_len(&self) -> usize {
+        9
+    }
+}
+
+#[cfg(feature = "half")]
+mod half {
+    use super::*;
+    use ::half::{bf16, f16};
+
+    // Widened to `FLOAT32`, as there is no half precision format in the protocol.
+    impl Packable for f16 {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            self.to_f32().pack(buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            5
+        }
+    }
+
+    impl Packable for bf16 {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            self.to_f32().pack(buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            5
+        }
+    }
 }
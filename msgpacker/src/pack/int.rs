@@ -1,34 +1,80 @@
-use super::{Format, Packable};
-use core::iter;
+use super::{
+    binary::{pack_bytes, packed_bytes_len, try_pack_bytes},
+    Format, PackBuf, PackError, Packable,
+};
+use core::{
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+    },
+    sync::atomic::{self, Ordering},
+};
 
 impl Packable for u8 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self <= 127 {
-            buf.extend(iter::once(self & Format::POSITIVE_FIXINT));
+            buf.put_u8(self & Format::POSITIVE_FIXINT);
             1
         } else {
-            buf.extend(iter::once(Format::UINT8).chain(iter::once(*self)));
+            buf.put_slice(&[Format::UINT8, *self]);
             2
         }
     }
+
+    fn packed_len(&self) -> usize {
+        if *self <= 127 {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn pack_slice<T>(slice: &[Self], buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        pack_bytes(slice, buf)
+    }
+
+    fn try_pack_slice<T>(slice: &[Self], buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        try_pack_bytes(slice, buf)
+    }
+
+    fn packed_slice_len(slice: &[Self]) -> usize {
+        packed_bytes_len(slice)
+    }
 }
 
 impl Packable for u16 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self <= 127 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8(*self as u8 & Format::POSITIVE_FIXINT);
+            1
+        } else if *self <= u8::MAX as u16 {
+            buf.put_slice(&[Format::UINT8, *self as u8]);
+            2
+        } else {
+            buf.put_u8(Format::UINT16);
+            buf.put_slice(&self.to_be_bytes());
+            3
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self <= 127 {
             1
         } else if *self <= u8::MAX as u16 {
-            buf.extend(iter::once(Format::UINT8).chain(iter::once(*self as u8)));
             2
         } else {
-            buf.extend(iter::once(Format::UINT16).chain(self.to_be_bytes()));
             3
         }
     }
@@ -37,19 +83,33 @@ impl Packable for u16 {
 impl Packable for u32 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self <= 127 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8(*self as u8 & Format::POSITIVE_FIXINT);
+            1
+        } else if *self <= u8::MAX as u32 {
+            buf.put_slice(&[Format::UINT8, *self as u8]);
+            2
+        } else if *self <= u16::MAX as u32 {
+            buf.put_u8(Format::UINT16);
+            buf.put_slice(&(*self as u16).to_be_bytes());
+            3
+        } else {
+            buf.put_u8(Format::UINT32);
+            buf.put_slice(&self.to_be_bytes());
+            5
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self <= 127 {
             1
         } else if *self <= u8::MAX as u32 {
-            buf.extend(iter::once(Format::UINT8).chain(iter::once(*self as u8)));
             2
         } else if *self <= u16::MAX as u32 {
-            buf.extend(iter::once(Format::UINT16).chain((*self as u16).to_be_bytes()));
             3
         } else {
-            buf.extend(iter::once(Format::UINT32).chain(self.to_be_bytes()));
             5
         }
     }
@@ -58,22 +118,39 @@ impl Packable for u32 {
 impl Packable for u64 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self <= 127 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8(*self as u8 & Format::POSITIVE_FIXINT);
+            1
+        } else if *self <= u8::MAX as u64 {
+            buf.put_slice(&[Format::UINT8, *self as u8]);
+            2
+        } else if *self <= u16::MAX as u64 {
+            buf.put_u8(Format::UINT16);
+            buf.put_slice(&(*self as u16).to_be_bytes());
+            3
+        } else if *self <= u32::MAX as u64 {
+            buf.put_u8(Format::UINT32);
+            buf.put_slice(&(*self as u32).to_be_bytes());
+            5
+        } else {
+            buf.put_u8(Format::UINT64);
+            buf.put_slice(&self.to_be_bytes());
+            9
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self <= 127 {
             1
         } else if *self <= u8::MAX as u64 {
-            buf.extend(iter::once(Format::UINT8).chain(iter::once(*self as u8)));
             2
         } else if *self <= u16::MAX as u64 {
-            buf.extend(iter::once(Format::UINT16).chain((*self as u16).to_be_bytes()));
             3
         } else if *self <= u32::MAX as u64 {
-            buf.extend(iter::once(Format::UINT32).chain((*self as u32).to_be_bytes()));
             5
         } else {
-            buf.extend(iter::once(Format::UINT64).chain(self.to_be_bytes()));
             9
         }
     }
@@ -82,29 +159,45 @@ impl Packable for u64 {
 impl Packable for u128 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self <= 127 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8(*self as u8 & Format::POSITIVE_FIXINT);
+            1
+        } else if *self <= u8::MAX as u128 {
+            buf.put_slice(&[Format::UINT8, *self as u8]);
+            2
+        } else if *self <= u16::MAX as u128 {
+            buf.put_u8(Format::UINT16);
+            buf.put_slice(&(*self as u16).to_be_bytes());
+            3
+        } else if *self <= u32::MAX as u128 {
+            buf.put_u8(Format::UINT32);
+            buf.put_slice(&(*self as u32).to_be_bytes());
+            5
+        } else if *self <= u64::MAX as u128 {
+            buf.put_u8(Format::UINT64);
+            buf.put_slice(&(*self as u64).to_be_bytes());
+            9
+        } else {
+            buf.put_slice(&[Format::BIN8, 16]);
+            buf.put_slice(&self.to_be_bytes());
+            18
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self <= 127 {
             1
         } else if *self <= u8::MAX as u128 {
-            buf.extend(iter::once(Format::UINT8).chain(iter::once(*self as u8)));
             2
         } else if *self <= u16::MAX as u128 {
-            buf.extend(iter::once(Format::UINT16).chain((*self as u16).to_be_bytes()));
             3
         } else if *self <= u32::MAX as u128 {
-            buf.extend(iter::once(Format::UINT32).chain((*self as u32).to_be_bytes()));
             5
         } else if *self <= u64::MAX as u128 {
-            buf.extend(iter::once(Format::UINT64).chain((*self as u64).to_be_bytes()));
             9
         } else {
-            buf.extend(
-                iter::once(Format::BIN8)
-                    .chain(iter::once(16))
-                    .chain(self.to_be_bytes()),
-            );
             18
         }
     }
@@ -113,22 +206,39 @@ impl Packable for u128 {
 impl Packable for usize {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self <= 127 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8(*self as u8 & Format::POSITIVE_FIXINT);
+            1
+        } else if *self <= u8::MAX as usize {
+            buf.put_slice(&[Format::UINT8, *self as u8]);
+            2
+        } else if *self <= u16::MAX as usize {
+            buf.put_u8(Format::UINT16);
+            buf.put_slice(&(*self as u16).to_be_bytes());
+            3
+        } else if *self <= u32::MAX as usize {
+            buf.put_u8(Format::UINT32);
+            buf.put_slice(&(*self as u32).to_be_bytes());
+            5
+        } else {
+            buf.put_u8(Format::UINT64);
+            buf.put_slice(&self.to_be_bytes());
+            9
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self <= 127 {
             1
         } else if *self <= u8::MAX as usize {
-            buf.extend(iter::once(Format::UINT8).chain(iter::once(*self as u8)));
             2
         } else if *self <= u16::MAX as usize {
-            buf.extend(iter::once(Format::UINT16).chain((*self as u16).to_be_bytes()));
             3
         } else if *self <= u32::MAX as usize {
-            buf.extend(iter::once(Format::UINT32).chain((*self as u32).to_be_bytes()));
             5
         } else {
-            buf.extend(iter::once(Format::UINT64).chain(self.to_be_bytes()));
             9
         }
     }
@@ -137,16 +247,24 @@ impl Packable for usize {
 impl Packable for i8 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self <= -33 {
-            buf.extend(iter::once(Format::INT8).chain(iter::once(*self as u8)));
+            buf.put_slice(&[Format::INT8, *self as u8]);
             2
         } else if *self <= -1 {
-            buf.extend(iter::once((*self | -32i8) as u8));
+            buf.put_u8((*self | -32i8) as u8);
             1
         } else {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8(*self as u8 & Format::POSITIVE_FIXINT);
+            1
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self <= -33 {
+            2
+        } else {
             1
         }
     }
@@ -155,23 +273,32 @@ impl Packable for i8 {
 impl Packable for i16 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self < i8::MIN as i16 {
-            buf.extend(iter::once(Format::INT16).chain(self.to_be_bytes()));
+            buf.put_u8(Format::INT16);
+            buf.put_slice(&self.to_be_bytes());
             3
         } else if *self <= -33 {
-            buf.extend(iter::once(Format::INT8).chain(iter::once((*self as i8) as u8)));
+            buf.put_slice(&[Format::INT8, (*self as i8) as u8]);
             2
         } else if *self <= -1 {
-            buf.extend(iter::once((*self as i8 | -32i8) as u8));
-            1
-        } else if *self <= i8::MAX as i16 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8((*self as i8 | -32i8) as u8);
             1
         } else {
-            buf.extend(iter::once(Format::INT16).chain(self.to_be_bytes()));
+            (*self as u16).pack(buf)
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self < i8::MIN as i16 {
             3
+        } else if *self <= -33 {
+            2
+        } else if *self <= -1 {
+            1
+        } else {
+            (*self as u16).packed_len()
         }
     }
 }
@@ -179,29 +306,38 @@ impl Packable for i16 {
 impl Packable for i32 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self < i16::MIN as i32 {
-            buf.extend(iter::once(Format::INT32).chain(self.to_be_bytes()));
+            buf.put_u8(Format::INT32);
+            buf.put_slice(&self.to_be_bytes());
             5
         } else if *self < i8::MIN as i32 {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
+            buf.put_u8(Format::INT16);
+            buf.put_slice(&(*self as i16).to_be_bytes());
             3
         } else if *self <= -33 {
-            buf.extend(iter::once(Format::INT8).chain(iter::once((*self as i8) as u8)));
+            buf.put_slice(&[Format::INT8, (*self as i8) as u8]);
             2
         } else if *self <= -1 {
-            buf.extend(iter::once((*self | -32i32) as u8));
-            1
-        } else if *self <= i8::MAX as i32 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8((*self | -32i32) as u8);
             1
-        } else if *self <= i16::MAX as i32 {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
-            3
         } else {
-            buf.extend(iter::once(Format::INT32).chain(self.to_be_bytes()));
+            (*self as u32).pack(buf)
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self < i16::MIN as i32 {
             5
+        } else if *self < i8::MIN as i32 {
+            3
+        } else if *self <= -33 {
+            2
+        } else if *self <= -1 {
+            1
+        } else {
+            (*self as u32).packed_len()
         }
     }
 }
@@ -209,35 +345,44 @@ impl Packable for i32 {
 impl Packable for i64 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self < i32::MIN as i64 {
-            buf.extend(iter::once(Format::INT64).chain(self.to_be_bytes()));
+            buf.put_u8(Format::INT64);
+            buf.put_slice(&self.to_be_bytes());
             9
         } else if *self < i16::MIN as i64 {
-            buf.extend(iter::once(Format::INT32).chain((*self as i32).to_be_bytes()));
+            buf.put_u8(Format::INT32);
+            buf.put_slice(&(*self as i32).to_be_bytes());
             5
         } else if *self < i8::MIN as i64 {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
+            buf.put_u8(Format::INT16);
+            buf.put_slice(&(*self as i16).to_be_bytes());
             3
         } else if *self <= -33 {
-            buf.extend(iter::once(Format::INT8).chain(iter::once((*self as i8) as u8)));
+            buf.put_slice(&[Format::INT8, (*self as i8) as u8]);
             2
         } else if *self <= -1 {
-            buf.extend(iter::once((*self | -32i64) as u8));
+            buf.put_u8((*self | -32i64) as u8);
             1
-        } else if *self <= i8::MAX as i64 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
-            1
-        } else if *self <= i16::MAX as i64 {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
-            3
-        } else if *self <= i32::MAX as i64 {
-            buf.extend(iter::once(Format::INT32).chain((*self as i32).to_be_bytes()));
-            5
         } else {
-            buf.extend(iter::once(Format::INT64).chain(self.to_be_bytes()));
+            (*self as u64).pack(buf)
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self < i32::MIN as i64 {
             9
+        } else if *self < i16::MIN as i64 {
+            5
+        } else if *self < i8::MIN as i64 {
+            3
+        } else if *self <= -33 {
+            2
+        } else if *self <= -1 {
+            1
+        } else {
+            (*self as u64).packed_len()
         }
     }
 }
@@ -245,48 +390,55 @@ impl Packable for i64 {
 impl Packable for i128 {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self < i64::MIN as i128 {
-            buf.extend(
-                iter::once(Format::BIN8)
-                    .chain(iter::once(16))
-                    .chain(self.to_be_bytes()),
-            );
+            buf.put_slice(&[Format::BIN8, 16]);
+            buf.put_slice(&self.to_be_bytes());
             18
         } else if *self < i32::MIN as i128 {
-            buf.extend(iter::once(Format::INT64).chain((*self as i64).to_be_bytes()));
+            buf.put_u8(Format::INT64);
+            buf.put_slice(&(*self as i64).to_be_bytes());
             9
         } else if *self < i16::MIN as i128 {
-            buf.extend(iter::once(Format::INT32).chain((*self as i32).to_be_bytes()));
+            buf.put_u8(Format::INT32);
+            buf.put_slice(&(*self as i32).to_be_bytes());
             5
         } else if *self < i8::MIN as i128 {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
+            buf.put_u8(Format::INT16);
+            buf.put_slice(&(*self as i16).to_be_bytes());
             3
         } else if *self <= -33 {
-            buf.extend(iter::once(Format::INT8).chain(iter::once((*self as i8) as u8)));
+            buf.put_slice(&[Format::INT8, (*self as i8) as u8]);
             2
         } else if *self <= -1 {
-            buf.extend(iter::once((*self | -32i128) as u8));
-            1
-        } else if *self <= i8::MAX as i128 {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8((*self | -32i128) as u8);
             1
-        } else if *self <= i16::MAX as i128 {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
-            3
-        } else if *self <= i32::MAX as i128 {
-            buf.extend(iter::once(Format::INT32).chain((*self as i32).to_be_bytes()));
-            5
-        } else if *self <= i64::MAX as i128 {
-            buf.extend(iter::once(Format::INT64).chain((*self as i64).to_be_bytes()));
+        } else if *self <= u64::MAX as i128 {
+            (*self as u64).pack(buf)
+        } else {
+            buf.put_slice(&[Format::BIN8, 16]);
+            buf.put_slice(&self.to_be_bytes());
+            18
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self < i64::MIN as i128 {
+            18
+        } else if *self < i32::MIN as i128 {
             9
+        } else if *self < i16::MIN as i128 {
+            5
+        } else if *self < i8::MIN as i128 {
+            3
+        } else if *self <= -33 {
+            2
+        } else if *self <= -1 {
+            1
+        } else if *self <= u64::MAX as i128 {
+            (*self as u64).packed_len()
         } else {
-            buf.extend(
-                iter::once(Format::BIN8)
-                    .chain(iter::once(16))
-                    .chain(self.to_be_bytes()),
-            );
             18
         }
     }
@@ -295,35 +447,200 @@ impl Packable for i128 {
 impl Packable for isize {
     fn pack<T>(&self, buf: &mut T) -> usize
     where
-        T: Extend<u8>,
+        T: PackBuf,
     {
         if *self < i32::MIN as isize {
-            buf.extend(iter::once(Format::INT64).chain(self.to_be_bytes()));
+            buf.put_u8(Format::INT64);
+            buf.put_slice(&self.to_be_bytes());
             9
         } else if *self < i16::MIN as isize {
-            buf.extend(iter::once(Format::INT32).chain((*self as i32).to_be_bytes()));
+            buf.put_u8(Format::INT32);
+            buf.put_slice(&(*self as i32).to_be_bytes());
             5
         } else if *self < i8::MIN as isize {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
+            buf.put_u8(Format::INT16);
+            buf.put_slice(&(*self as i16).to_be_bytes());
             3
         } else if *self <= -33 {
-            buf.extend(iter::once(Format::INT8).chain(iter::once((*self as i8) as u8)));
+            buf.put_slice(&[Format::INT8, (*self as i8) as u8]);
             2
         } else if *self <= -1 {
-            buf.extend(iter::once((*self | -32isize) as u8));
-            1
-        } else if *self <= i8::MAX as isize {
-            buf.extend(iter::once(*self as u8 & Format::POSITIVE_FIXINT));
+            buf.put_u8((*self | -32isize) as u8);
             1
-        } else if *self <= i16::MAX as isize {
-            buf.extend(iter::once(Format::INT16).chain((*self as i16).to_be_bytes()));
-            3
-        } else if *self <= i32::MAX as isize {
-            buf.extend(iter::once(Format::INT32).chain((*self as i32).to_be_bytes()));
-            5
         } else {
-            buf.extend(iter::once(Format::INT64).chain(self.to_be_bytes()));
+            (*self as usize).pack(buf)
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        if *self < i32::MIN as isize {
             9
+        } else if *self < i16::MIN as isize {
+            5
+        } else if *self < i8::MIN as isize {
+            3
+        } else if *self <= -33 {
+            2
+        } else if *self <= -1 {
+            1
+        } else {
+            (*self as usize).packed_len()
+        }
+    }
+}
+
+macro_rules! non_zero {
+    ($t:ty) => {
+        impl Packable for $t {
+            fn pack<T>(&self, buf: &mut T) -> usize
+            where
+                T: PackBuf,
+            {
+                self.get().pack(buf)
+            }
+
+            fn packed_len(&self) -> usize {
+                self.get().packed_len()
+            }
+        }
+    };
+}
+
+non_zero!(NonZeroU8);
+non_zero!(NonZeroU16);
+non_zero!(NonZeroU32);
+non_zero!(NonZeroU64);
+non_zero!(NonZeroU128);
+non_zero!(NonZeroUsize);
+non_zero!(NonZeroI8);
+non_zero!(NonZeroI16);
+non_zero!(NonZeroI32);
+non_zero!(NonZeroI64);
+non_zero!(NonZeroI128);
+non_zero!(NonZeroIsize);
+
+macro_rules! wrapper {
+    ($w:ident) => {
+        impl<X> Packable for $w<X>
+        where
+            X: Packable,
+        {
+            fn pack<T>(&self, buf: &mut T) -> usize
+            where
+                T: PackBuf,
+            {
+                self.0.pack(buf)
+            }
+
+            fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+            where
+                T: PackBuf,
+            {
+                self.0.try_pack(buf)
+            }
+
+            fn packed_len(&self) -> usize {
+                self.0.packed_len()
+            }
+        }
+    };
+}
+
+wrapper!(Wrapping);
+wrapper!(Saturating);
+
+macro_rules! atomic {
+    ($t:ident, $size:literal) => {
+        #[cfg(target_has_atomic = $size)]
+        impl Packable for atomic::$t {
+            fn pack<T>(&self, buf: &mut T) -> usize
+            where
+                T: PackBuf,
+            {
+                self.load(Ordering::Relaxed).pack(buf)
+            }
+
+            fn packed_len(&self) -> usize {
+                self.load(Ordering::Relaxed).packed_len()
+            }
+        }
+    };
+}
+
+atomic!(AtomicU8, "8");
+atomic!(AtomicU16, "16");
+atomic!(AtomicU32, "32");
+atomic!(AtomicU64, "64");
+atomic!(AtomicUsize, "ptr");
+atomic!(AtomicI8, "8");
+atomic!(AtomicI16, "16");
+atomic!(AtomicI32, "32");
+atomic!(AtomicI64, "64");
+atomic!(AtomicIsize, "ptr");
+
+#[cfg(feature = "num-bigint")]
+mod num_bigint {
+    use super::*;
+    use ::num_bigint::{BigInt, BigUint};
+
+    // Packed as an `i128` if the value fits; otherwise, as binary of the big-endian two's
+    // complement bytes, which is the `i128` binary encoding extended to any length.
+    impl Packable for BigInt {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            match i128::try_from(self) {
+                Ok(x) => x.pack(buf),
+                Err(_) => self.to_signed_bytes_be().pack(buf),
+            }
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            match i128::try_from(self) {
+                Ok(x) => x.try_pack(buf),
+                Err(_) => self.to_signed_bytes_be().try_pack(buf),
+            }
+        }
+
+        fn packed_len(&self) -> usize {
+            match i128::try_from(self) {
+                Ok(x) => x.packed_len(),
+                Err(_) => self.to_signed_bytes_be().packed_len(),
+            }
+        }
+    }
+
+    // Packed as an `u128` if the value fits; otherwise, as binary of the big-endian bytes.
+    impl Packable for BigUint {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            match u128::try_from(self) {
+                Ok(x) => x.pack(buf),
+                Err(_) => self.to_bytes_be().pack(buf),
+            }
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            match u128::try_from(self) {
+                Ok(x) => x.try_pack(buf),
+                Err(_) => self.to_bytes_be().try_pack(buf),
+            }
+        }
+
+        fn packed_len(&self) -> usize {
+            match u128::try_from(self) {
+                Ok(x) => x.packed_len(),
+                Err(_) => self.to_bytes_be().packed_len(),
+            }
         }
     }
 }
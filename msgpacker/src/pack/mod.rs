@@ -1,9 +1,70 @@
-use super::{Format, Packable};
+use super::{Format, PackBuf, PackError, Packable};
 
 mod binary;
+mod bulk;
 mod collections;
 mod common;
 mod float;
 mod int;
+mod net;
+#[cfg(feature = "std")]
+mod os;
+mod pointer;
+mod range;
 
-pub use collections::{pack_array, pack_map};
+pub use bulk::{
+    pack_f32_slice, pack_f64_slice, pack_i16_slice, pack_i32_slice, pack_i64_slice, pack_u16_slice,
+    pack_u32_slice, pack_u64_slice,
+};
+pub use collections::{
+    pack_array, pack_map, packed_array_len, packed_map_len, try_pack_array, try_pack_map,
+};
+
+#[cfg(feature = "alloc")]
+pub use collections::{
+    pack_array_sorted, pack_map_sorted, try_pack_array_sorted, try_pack_map_sorted,
+};
+
+/// Cursor over a slice that records an overflow instead of writing past its end.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+    overflow: bool,
+}
+
+impl PackBuf for SliceWriter<'_> {
+    fn put_u8(&mut self, b: u8) {
+        self.put_slice(&[b]);
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        match self.buf.get_mut(self.len..self.len + s.len()) {
+            Some(dst) if !self.overflow => {
+                dst.copy_from_slice(s);
+                self.len += s.len();
+            }
+            _ => self.overflow = true,
+        }
+    }
+}
+
+/// Packs a value into the provided slice, returning the amount of written bytes.
+///
+/// Fails with [PackError::BufferTooShort] if the slice cannot hold the packed value, or with
+/// [PackError::LengthOverflow] as [Packable::try_pack]. The slice might contain a partially packed
+/// value in that case.
+pub fn pack_into_slice<X>(value: &X, buf: &mut [u8]) -> Result<usize, PackError>
+where
+    X: Packable + ?Sized,
+{
+    let mut writer = SliceWriter {
+        buf,
+        len: 0,
+        overflow: false,
+    };
+    let n = value.try_pack(&mut writer)?;
+    if writer.overflow {
+        return Err(PackError::BufferTooShort);
+    }
+    Ok(n)
+}
@@ -0,0 +1,98 @@
+use super::{PackBuf, Packable};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// Fixed array header of the address and port pair of a socket address.
+const SOCKET: u8 = 0x92;
+
+impl Packable for Ipv4Addr {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        self.octets()[..].pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        6
+    }
+}
+
+impl Packable for Ipv6Addr {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        self.octets()[..].pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        18
+    }
+}
+
+impl Packable for IpAddr {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        match self {
+            IpAddr::V4(ip) => ip.pack(buf),
+            IpAddr::V6(ip) => ip.pack(buf),
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        match self {
+            IpAddr::V4(ip) => ip.packed_len(),
+            IpAddr::V6(ip) => ip.packed_len(),
+        }
+    }
+}
+
+impl Packable for SocketAddrV4 {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        buf.put_u8(SOCKET);
+        1 + self.ip().pack(buf) + self.port().pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        1 + self.ip().packed_len() + self.port().packed_len()
+    }
+}
+
+// The flow information and the scope id are not part of the encoding.
+impl Packable for SocketAddrV6 {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        buf.put_u8(SOCKET);
+        1 + self.ip().pack(buf) + self.port().pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        1 + self.ip().packed_len() + self.port().packed_len()
+    }
+}
+
+impl Packable for SocketAddr {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        match self {
+            SocketAddr::V4(addr) => addr.pack(buf),
+            SocketAddr::V6(addr) => addr.pack(buf),
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        match self {
+            SocketAddr::V4(addr) => addr.packed_len(),
+            SocketAddr::V6(addr) => addr.packed_len(),
+        }
+    }
+}
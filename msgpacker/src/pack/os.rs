@@ -0,0 +1,96 @@
+use super::{PackBuf, PackError, Packable};
+use std::{
+    ffi::{CStr, CString, OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
+
+impl Packable for OsStr {
+    #[allow(unreachable_code)]
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        if let Ok(n) = self.try_pack(buf) {
+            return n;
+        }
+        #[cfg(feature = "strict")]
+        panic!("strict serialization enabled; the OS string cannot be packed");
+        0
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        if let Some(s) = self.to_str() {
+            return s.try_pack(buf);
+        }
+        // the bytes of a unix OS string are arbitrary, and can be packed as binary
+        #[cfg(unix)]
+        return self.as_bytes().try_pack(buf);
+        #[cfg(not(unix))]
+        Err(PackError::InvalidUtf8)
+    }
+
+    fn packed_len(&self) -> usize {
+        match self.to_str() {
+            Some(s) => s.packed_len(),
+            #[cfg(unix)]
+            None => self.as_bytes().packed_len(),
+            #[cfg(not(unix))]
+            None => 0,
+        }
+    }
+}
+
+impl Packable for CStr {
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        self.to_bytes().pack(buf)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        self.to_bytes().try_pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        self.to_bytes().packed_len()
+    }
+}
+
+macro_rules! deref {
+    ($t:ty, $f:ident) => {
+        impl Packable for $t {
+            fn pack<T>(&self, buf: &mut T) -> usize
+            where
+                T: PackBuf,
+            {
+                self.$f().pack(buf)
+            }
+
+            fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+            where
+                T: PackBuf,
+            {
+                self.$f().try_pack(buf)
+            }
+
+            fn packed_len(&self) -> usize {
+                self.$f().packed_len()
+            }
+        }
+    };
+}
+
+deref!(OsString, as_os_str);
+deref!(Path, as_os_str);
+deref!(PathBuf, as_os_str);
+deref!(CString, as_c_str);
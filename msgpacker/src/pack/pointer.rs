@@ -0,0 +1,134 @@
+use super::{PackBuf, PackError, Packable};
+use core::{
+    cell::{Cell, RefCell},
+    cmp::Reverse,
+};
+
+impl<X> Packable for Cell<X>
+where
+    X: Packable + Copy,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        self.get().pack(buf)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        self.get().try_pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        self.get().packed_len()
+    }
+}
+
+impl<X> Packable for RefCell<X>
+where
+    X: Packable + ?Sized,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        self.borrow().pack(buf)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        self.borrow().try_pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        self.borrow().packed_len()
+    }
+}
+
+impl<X> Packable for Reverse<X>
+where
+    X: Packable,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        self.0.pack(buf)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        self.0.try_pack(buf)
+    }
+
+    fn packed_len(&self) -> usize {
+        self.0.packed_len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    use super::*;
+    use ::alloc::{borrow::Cow, borrow::ToOwned, boxed::Box, rc::Rc, sync::Arc};
+
+    macro_rules! pointer {
+        ($p:ident) => {
+            impl<X> Packable for $p<X>
+            where
+                X: Packable + ?Sized,
+            {
+                fn pack<T>(&self, buf: &mut T) -> usize
+                where
+                    T: PackBuf,
+                {
+                    X::pack(self, buf)
+                }
+
+                fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+                where
+                    T: PackBuf,
+                {
+                    X::try_pack(self, buf)
+                }
+
+                fn packed_len(&self) -> usize {
+                    X::packed_len(self)
+                }
+            }
+        };
+    }
+
+    pointer!(Box);
+    pointer!(Rc);
+    pointer!(Arc);
+
+    impl<'a, X> Packable for Cow<'a, X>
+    where
+        X: Packable + ToOwned + ?Sized,
+    {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            X::pack(self, buf)
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            X::try_pack(self, buf)
+        }
+
+        fn packed_len(&self) -> usize {
+            X::packed_len(self)
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use super::{PackBuf, PackError, Packable};
+use core::ops::{Bound, Range, RangeInclusive};
+
+/// Fixed array header of the start and end pair of a range.
+const PAIR: u8 = 0x92;
+
+impl<X> Packable for Range<X>
+where
+    X: Packable,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        buf.put_u8(PAIR);
+        1 + self.start.pack(buf) + self.end.pack(buf)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        buf.put_u8(PAIR);
+        Ok(1 + self.start.try_pack(buf)? + self.end.try_pack(buf)?)
+    }
+
+    fn packed_len(&self) -> usize {
+        1 + self.start.packed_len() + self.end.packed_len()
+    }
+}
+
+impl<X> Packable for RangeInclusive<X>
+where
+    X: Packable,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        buf.put_u8(PAIR);
+        1 + self.start().pack(buf) + self.end().pack(buf)
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        buf.put_u8(PAIR);
+        Ok(1 + self.start().try_pack(buf)? + self.end().try_pack(buf)?)
+    }
+
+    fn packed_len(&self) -> usize {
+        1 + self.start().packed_len() + self.end().packed_len()
+    }
+}
+
+impl<X> Packable for Bound<X>
+where
+    X: Packable,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        match self {
+            Bound::Included(x) => 0u32.pack(buf) + x.pack(buf),
+            Bound::Excluded(x) => 1u32.pack(buf) + x.pack(buf),
+            Bound::Unbounded => 2u32.pack(buf),
+        }
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        match self {
+            Bound::Included(x) => Ok(0u32.pack(buf) + x.try_pack(buf)?),
+            Bound::Excluded(x) => Ok(1u32.pack(buf) + x.try_pack(buf)?),
+            Bound::Unbounded => Ok(2u32.pack(buf)),
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        match self {
+            Bound::Included(x) | Bound::Excluded(x) => 1 + x.packed_len(),
+            Bound::Unbounded => 1,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use super::{
+    helpers::{take_byte, take_byte_iter},
+    Error, PackBuf, PackError, Packable, Unpackable,
+};
+
+/// Fixed array header of an empty array.
+const NONE: u8 = 0x90;
+/// Fixed array header of a single element array.
+const SOME: u8 = 0x91;
+
+/// An option packed as an array of zero or one elements.
+///
+/// A plain [Option] packs `None` as nil and `Some` as the inner value, so `Some(None)` of a nested
+/// option is indistinguishable from `None`, and values that pack into zero bytes, as `()`, cannot
+/// be recovered. This wrapper trades one byte of overhead for an unambiguous encoding.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaggedOption<X>(pub Option<X>);
+
+impl<X> From<Option<X>> for TaggedOption<X> {
+    fn from(x: Option<X>) -> Self {
+        Self(x)
+    }
+}
+
+impl<X> From<TaggedOption<X>> for Option<X> {
+    fn from(x: TaggedOption<X>) -> Self {
+        x.0
+    }
+}
+
+impl<X> Packable for TaggedOption<X>
+where
+    X: Packable,
+{
+    fn pack<T>(&self, buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        match &self.0 {
+            Some(x) => {
+                buf.put_u8(SOME);
+                1 + x.pack(buf)
+            }
+            None => {
+                buf.put_u8(NONE);
+                1
+            }
+        }
+    }
+
+    fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        match &self.0 {
+            Some(x) => {
+                buf.put_u8(SOME);
+                Ok(1 + x.try_pack(buf)?)
+            }
+            None => {
+                buf.put_u8(NONE);
+                Ok(1)
+            }
+        }
+    }
+
+    fn packed_len(&self) -> usize {
+        1 + self.0.as_ref().map(X::packed_len).unwrap_or(0)
+    }
+}
+
+impl<X> Unpackable for TaggedOption<X>
+where
+    X: Unpackable,
+{
+    type Error = <X as Unpackable>::Error;
+
+    fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        match take_byte(&mut buf)? {
+            SOME => X::unpack(buf).map(|(n, x)| (n + 1, Self(Some(x)))),
+            NONE => Ok((1, Self(None))),
+            _ => Err(Error::UnexpectedFormatTag.into()),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter();
+        match take_byte_iter(bytes.by_ref())? {
+            SOME => X::unpack_iter(bytes).map(|(n, x)| (n + 1, Self(Some(x)))),
+            NONE => Ok((1, Self(None))),
+            _ => Err(Error::UnexpectedFormatTag.into()),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use super::{helpers::take_num, Error, Format, PackBuf};
+
+#[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+use super::helpers::{take_buffer, take_byte_iter, take_ext_header, take_ext_header_iter};
+
+/// Protocol reserved extension type of the timestamps.
+pub const TIMESTAMP: i8 = -1;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Packs a timestamp of `secs` seconds and `nanos` nanoseconds since the Unix epoch, using the
+/// smallest of the timestamp 32, 64 and 96 formats.
+pub fn pack_timestamp<T>(buf: &mut T, secs: i64, nanos: u32) -> usize
+where
+    T: PackBuf,
+{
+    if (0..1i64 << 34).contains(&secs) {
+        if nanos == 0 && secs <= u32::MAX as i64 {
+            buf.put_slice(&[Format::FIXEXT4, TIMESTAMP as u8]);
+            buf.put_slice(&(secs as u32).to_be_bytes());
+            6
+        } else {
+            let data = ((nanos as u64) << 34) | secs as u64;
+            buf.put_slice(&[Format::FIXEXT8, TIMESTAMP as u8]);
+            buf.put_slice(&data.to_be_bytes());
+            10
+        }
+    } else {
+        buf.put_slice(&[Format::EXT8, 12, TIMESTAMP as u8]);
+        buf.put_slice(&nanos.to_be_bytes());
+        buf.put_slice(&secs.to_be_bytes());
+        15
+    }
+}
+
+/// Returns the amount of bytes [pack_timestamp] will write.
+pub fn timestamp_len(secs: i64, nanos: u32) -> usize {
+    if (0..1i64 << 34).contains(&secs) {
+        if nanos == 0 && secs <= u32::MAX as i64 {
+            6
+        } else {
+            10
+        }
+    } else {
+        15
+    }
+}
+
+/// Decodes the payload of a timestamp extension into seconds and nanoseconds since the Unix epoch.
+///
+/// Fails with [Error::InvalidExtension] if the payload length isn't one of the timestamp formats,
+/// or if the nanoseconds are not smaller than one second.
+pub fn decode_timestamp(mut data: &[u8]) -> Result<(i64, u32), Error> {
+    let (secs, nanos) = match data.len() {
+        4 => (take_num(&mut data, u32::from_be_bytes)? as i64, 0),
+        8 => {
+            let data = take_num(&mut data, u64::from_be_bytes)?;
+            ((data & ((1u64 << 34) - 1)) as i64, (data >> 34) as u32)
+        }
+        12 => {
+            let nanos = take_num(&mut data, u32::from_be_bytes)?;
+            let secs = take_num(&mut data, i64::from_be_bytes)?;
+            (secs, nanos)
+        }
+        _ => return Err(Error::InvalidExtension),
+    };
+    if nanos >= NANOS_PER_SEC {
+        return Err(Error::InvalidExtension);
+    }
+    Ok((secs, nanos))
+}
+
+#[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+/// Takes a timestamp extension, returning the amount of read bytes, the seconds and the
+/// nanoseconds.
+pub fn take_timestamp(buf: &mut &[u8]) -> Result<(usize, i64, u32), Error> {
+    let (n, t, len) = take_ext_header(buf)?;
+    if t != TIMESTAMP {
+        return Err(Error::InvalidExtension);
+    }
+    let (secs, nanos) = decode_timestamp(take_buffer(buf, len)?)?;
+    Ok((n + len, secs, nanos))
+}
+
+#[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+/// Takes a timestamp extension, returning the amount of read bytes, the seconds and the
+/// nanoseconds.
+pub fn take_timestamp_iter<I>(mut bytes: I) -> Result<(usize, i64, u32), Error>
+where
+    I: Iterator<Item = u8>,
+{
+    let (n, t, len) = take_ext_header_iter(bytes.by_ref())?;
+    if t != TIMESTAMP || !matches!(len, 4 | 8 | 12) {
+        return Err(Error::InvalidExtension);
+    }
+    let mut data = [0u8; 12];
+    for b in &mut data[..len] {
+        *b = take_byte_iter(bytes.by_ref())?;
+    }
+    let (secs, nanos) = decode_timestamp(&data[..len])?;
+    Ok((n + len, secs, nanos))
+}
+
+#[cfg(feature = "std")]
+mod std {
+    use super::*;
+    use crate::{PackError, Packable, Unpackable};
+    use ::std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    /// Splits the time into seconds and nanoseconds since the Unix epoch, with the nanoseconds
+    /// always counting forward.
+    fn split(t: &SystemTime) -> Option<(i64, u32)> {
+        match t.duration_since(UNIX_EPOCH) {
+            Ok(d) => Some((i64::try_from(d.as_secs()).ok()?, d.subsec_nanos())),
+            Err(e) => {
+                let d = e.duration();
+                let secs = 0i64.checked_sub_unsigned(d.as_secs())?;
+                match d.subsec_nanos() {
+                    0 => Some((secs, 0)),
+                    n => Some((secs.checked_sub(1)?, NANOS_PER_SEC - n)),
+                }
+            }
+        }
+    }
+
+    fn join(secs: i64, nanos: u32) -> Result<SystemTime, Error> {
+        let t = if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+        } else {
+            Duration::from_secs(secs.unsigned_abs())
+                .checked_sub(Duration::from_nanos(nanos as u64))
+                .and_then(|d| UNIX_EPOCH.checked_sub(d))
+        };
+        t.ok_or(Error::InvalidExtension)
+    }
+
+    impl Packable for SystemTime {
+        #[allow(unreachable_code)]
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            match split(self) {
+                Some((secs, nanos)) => pack_timestamp(buf, secs, nanos),
+                None => {
+                    #[cfg(feature = "strict")]
+                    panic!("strict serialization enabled; the timestamp is out of range");
+                    0
+                }
+            }
+        }
+
+        fn try_pack<T>(&self, buf: &mut T) -> Result<usize, PackError>
+        where
+            T: PackBuf,
+        {
+            let (secs, nanos) = split(self).ok_or(PackError::TimestampOverflow)?;
+            Ok(pack_timestamp(buf, secs, nanos))
+        }
+
+        fn packed_len(&self) -> usize {
+            split(self)
+                .map(|(secs, nanos)| timestamp_len(secs, nanos))
+                .unwrap_or(0)
+        }
+    }
+
+    impl Unpackable for SystemTime {
+        type Error = Error;
+
+        fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            let (n, secs, nanos) = take_timestamp(&mut buf)?;
+            Ok((n, join(secs, nanos)?))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let (n, secs, nanos) = take_timestamp_iter(bytes.into_iter())?;
+            Ok((n, join(secs, nanos)?))
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use super::*;
+    use crate::{Packable, Unpackable};
+    use ::chrono::{DateTime, Utc};
+
+    /// Splits the time into seconds and nanoseconds since the Unix epoch.
+    ///
+    /// Leap seconds are represented by chrono with nanoseconds past one second; they are carried
+    /// into the seconds, as the protocol doesn't support them.
+    fn split(t: &DateTime<Utc>) -> (i64, u32) {
+        let (secs, nanos) = (t.timestamp(), t.timestamp_subsec_nanos());
+        if nanos >= NANOS_PER_SEC {
+            (secs + 1, nanos - NANOS_PER_SEC)
+        } else {
+            (secs, nanos)
+        }
+    }
+
+    impl Packable for DateTime<Utc> {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            let (secs, nanos) = split(self);
+            pack_timestamp(buf, secs, nanos)
+        }
+
+        fn packed_len(&self) -> usize {
+            let (secs, nanos) = split(self);
+            timestamp_len(secs, nanos)
+        }
+    }
+
+    impl Unpackable for DateTime<Utc> {
+        type Error = Error;
+
+        fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            let (n, secs, nanos) = take_timestamp(&mut buf)?;
+            let t = DateTime::from_timestamp(secs, nanos).ok_or(Error::InvalidExtension)?;
+            Ok((n, t))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let (n, secs, nanos) = take_timestamp_iter(bytes.into_iter())?;
+            let t = DateTime::from_timestamp(secs, nanos).ok_or(Error::InvalidExtension)?;
+            Ok((n, t))
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time {
+    use super::*;
+    use crate::{Packable, Unpackable};
+    use ::time::OffsetDateTime;
+
+    /// Joins the seconds and nanoseconds since the Unix epoch into a UTC date time.
+    fn join(secs: i64, nanos: u32) -> Result<OffsetDateTime, Error> {
+        let nanos = secs as i128 * NANOS_PER_SEC as i128 + nanos as i128;
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| Error::InvalidExtension)
+    }
+
+    impl Packable for OffsetDateTime {
+        fn pack<T>(&self, buf: &mut T) -> usize
+        where
+            T: PackBuf,
+        {
+            pack_timestamp(buf, self.unix_timestamp(), self.nanosecond())
+        }
+
+        fn packed_len(&self) -> usize {
+            timestamp_len(self.unix_timestamp(), self.nanosecond())
+        }
+    }
+
+    impl Unpackable for OffsetDateTime {
+        type Error = Error;
+
+        fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            let (n, secs, nanos) = take_timestamp(&mut buf)?;
+            Ok((n, join(secs, nanos)?))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let (n, secs, nanos) = take_timestamp_iter(bytes.into_iter())?;
+            Ok((n, join(secs, nanos)?))
+        }
+    }
+}
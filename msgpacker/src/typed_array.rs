@@ -0,0 +1,487 @@
+use super::{
+    helpers::{
+        ext_header_len, pack_ext_header, take_buffer, take_buffer_iter, take_ext_header,
+        take_ext_header_iter,
+    },
+    Error, Extension, PackBuf, PackError, Packable, Unpackable,
+};
+use alloc::{borrow::Cow, vec::Vec};
+use core::{mem, ops::Deref, ptr, slice};
+
+/// Endianness flag of the elements of a typed array payload.
+const LITTLE_ENDIAN: u8 = 0;
+const BIG_ENDIAN: u8 = 1;
+
+#[cfg(target_endian = "little")]
+const NATIVE_ENDIAN: u8 = LITTLE_ENDIAN;
+#[cfg(target_endian = "big")]
+const NATIVE_ENDIAN: u8 = BIG_ENDIAN;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A numeric type that can be an element of a [TypedArray].
+///
+/// This trait is sealed; it is implemented for the primitive integers up to 64 bits, `f32` and
+/// `f64`.
+pub trait TypedArrayElement: sealed::Sealed + Copy + 'static {
+    /// Element kind, as written in the payload of the extension.
+    const KIND: u8;
+
+    #[doc(hidden)]
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! element {
+    ($t:ty, $kind:expr) => {
+        impl sealed::Sealed for $t {}
+
+        impl TypedArrayElement for $t {
+            const KIND: u8 = $kind;
+
+            fn swap_bytes(self) -> Self {
+                <$t>::swap_bytes(self)
+            }
+        }
+    };
+    ($t:ty, $kind:expr, $bits:ty) => {
+        impl sealed::Sealed for $t {}
+
+        impl TypedArrayElement for $t {
+            const KIND: u8 = $kind;
+
+            fn swap_bytes(self) -> Self {
+                <$t>::from_bits(<$bits>::swap_bytes(self.to_bits()))
+            }
+        }
+    };
+}
+
+element!(u8, 0x00);
+element!(u16, 0x01);
+element!(u32, 0x02);
+element!(u64, 0x03);
+element!(i8, 0x10);
+element!(i16, 0x11);
+element!(i32, 0x12);
+element!(i64, 0x13);
+element!(f32, 0x22, u32);
+element!(f64, 0x23, u64);
+
+/// A homogeneous numeric array, packed as an extension with the raw bytes of its elements.
+///
+/// **The extension type [TypedArray::TYPE] (`0x54`) is reserved by this crate**. It belongs to the
+/// range of application defined types, so applications using typed arrays must not assign it to
+/// their own extensions.
+///
+/// The payload of the extension is the element kind ([TypedArrayElement::KIND]), followed by the
+/// endianness of the elements (`0` for little endian, `1` for big endian), and the elements
+/// themselves. Arrays are always packed in little endian, so the output doesn't depend on the
+/// platform; big endian payloads are accepted as well, and byte-swapped when they don't match the
+/// platform.
+///
+/// [TypedArray::unpack_borrowed] will borrow the elements from the buffer, without copying them,
+/// if they are aligned and in the native endianness; for the packed arrays, this means on little
+/// endian platforms only.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedArray<'a, T>(pub Cow<'a, [T]>)
+where
+    T: TypedArrayElement;
+
+impl<'a, T> TypedArray<'a, T>
+where
+    T: TypedArrayElement,
+{
+    /// Extension type of the typed arrays, reserved by this crate.
+    pub const TYPE: i8 = 0x54;
+
+    /// Creates a new typed array from either a borrowed slice or a vector.
+    pub fn new<V>(values: V) -> Self
+    where
+        V: Into<Cow<'a, [T]>>,
+    {
+        Self(values.into())
+    }
+
+    /// Returns `true` if the elements are borrowed.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+
+    /// Converts the array into an owned vector of elements.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0.into_owned()
+    }
+
+    /// Converts the array into one that owns its elements.
+    pub fn into_owned(self) -> TypedArray<'static, T> {
+        TypedArray(Cow::Owned(self.0.into_owned()))
+    }
+
+    /// Unpacks a typed array from the buffer, returning the deserialized value and the amount of
+    /// read bytes.
+    ///
+    /// The elements are borrowed from the buffer if they are aligned and in the native endianness,
+    /// and copied otherwise.
+    pub fn unpack_borrowed(mut buf: &'a [u8]) -> Result<(usize, Self), Error> {
+        let (n, t, len) = take_ext_header(&mut buf)?;
+        if t != Self::TYPE {
+            return Err(Error::InvalidExtension);
+        }
+        let payload = take_buffer(&mut buf, len)?;
+        Ok((n + len, Self(decode(payload)?)))
+    }
+
+    /// Writes the raw bytes of the elements in little endian.
+    fn put_elements<B>(&self, buf: &mut B)
+    where
+        B: PackBuf,
+    {
+        #[cfg(target_endian = "little")]
+        {
+            // Safety: the elements are plain numbers without padding
+            let bytes = unsafe {
+                slice::from_raw_parts(self.0.as_ptr() as *const u8, mem::size_of_val(&*self.0))
+            };
+            buf.put_slice(bytes);
+        }
+
+        #[cfg(target_endian = "big")]
+        for v in self.0.iter() {
+            let v = v.swap_bytes();
+            // Safety: the element is a plain number without padding
+            let bytes =
+                unsafe { slice::from_raw_parts(&v as *const T as *const u8, mem::size_of::<T>()) };
+            buf.put_slice(bytes);
+        }
+    }
+
+    fn payload_len(&self) -> usize {
+        2 + mem::size_of_val(&*self.0)
+    }
+}
+
+fn decode<T>(payload: &[u8]) -> Result<Cow<'_, [T]>, Error>
+where
+    T: TypedArrayElement,
+{
+    let size = mem::size_of::<T>();
+    let (kind, endian, data) = match payload {
+        [kind, endian, data @ ..] => (*kind, *endian, data),
+        _ => return Err(Error::InvalidExtension),
+    };
+    if kind != T::KIND || endian > BIG_ENDIAN || data.len() % size != 0 {
+        return Err(Error::InvalidExtension);
+    }
+
+    let len = data.len() / size;
+    let native = endian == NATIVE_ENDIAN;
+    if native && data.as_ptr().align_offset(mem::align_of::<T>()) == 0 {
+        // Safety: the pointer is aligned, holds `len` elements, and every bit pattern is a valid
+        // element
+        let values = unsafe { slice::from_raw_parts(data.as_ptr() as *const T, len) };
+        return Ok(Cow::Borrowed(values));
+    }
+
+    let values = data
+        .chunks_exact(size)
+        .map(|c| {
+            // Safety: the chunk holds exactly one element
+            let v = unsafe { ptr::read_unaligned(c.as_ptr() as *const T) };
+            if native {
+                v
+            } else {
+                v.swap_bytes()
+            }
+        })
+        .collect();
+    Ok(Cow::Owned(values))
+}
+
+impl<'a, T> Deref for TypedArray<'a, T>
+where
+    T: TypedArrayElement,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'a, T> From<&'a [T]> for TypedArray<'a, T>
+where
+    T: TypedArrayElement,
+{
+    fn from(values: &'a [T]) -> Self {
+        Self(Cow::Borrowed(values))
+    }
+}
+
+impl<T> From<Vec<T>> for TypedArray<'static, T>
+where
+    T: TypedArrayElement,
+{
+    fn from(values: Vec<T>) -> Self {
+        Self(Cow::Owned(values))
+    }
+}
+
+impl<'a, T> From<TypedArray<'a, T>> for Extension
+where
+    T: TypedArrayElement,
+{
+    fn from(array: TypedArray<'a, T>) -> Self {
+        let mut payload = Vec::with_capacity(array.payload_len());
+        payload.extend_from_slice(&[T::KIND, LITTLE_ENDIAN]);
+        array.put_elements(&mut payload);
+        Extension::Ext(TypedArray::<T>::TYPE, payload)
+    }
+}
+
+impl<'a, T> TryFrom<&'a Extension> for TypedArray<'a, T>
+where
+    T: TypedArrayElement,
+{
+    type Error = Error;
+
+    fn try_from(ext: &'a Extension) -> Result<Self, Self::Error> {
+        match ext {
+            Extension::Ext(t, payload) if *t == Self::TYPE => decode(payload).map(Self),
+            _ => Err(Error::InvalidExtension),
+        }
+    }
+}
+
+impl<'a, T> Packable for TypedArray<'a, T>
+where
+    T: TypedArrayElement,
+{
+    #[allow(unreachable_code)]
+    fn pack<B>(&self, buf: &mut B) -> usize
+    where
+        B: PackBuf,
+    {
+        if let Ok(n) = self.try_pack(buf) {
+            return n;
+        }
+        #[cfg(feature = "strict")]
+        panic!("strict serialization enabled; the buffer is too large");
+        0
+    }
+
+    fn try_pack<B>(&self, buf: &mut B) -> Result<usize, PackError>
+    where
+        B: PackBuf,
+    {
+        let len = self.payload_len();
+        let n = pack_ext_header(buf, Self::TYPE, len)?;
+        buf.reserve(len);
+        buf.put_slice(&[T::KIND, LITTLE_ENDIAN]);
+        self.put_elements(buf);
+        Ok(n + len)
+    }
+
+    fn packed_len(&self) -> usize {
+        let len = self.payload_len();
+        match ext_header_len(len) {
+            0 => 0,
+            n => n + len,
+        }
+    }
+}
+
+impl<T> Unpackable for TypedArray<'static, T>
+where
+    T: TypedArrayElement,
+{
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        TypedArray::unpack_borrowed(buf).map(|(n, a)| (n, a.into_owned()))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter();
+        let (n, t, len) = take_ext_header_iter(bytes.by_ref())?;
+        if t != Self::TYPE {
+            return Err(Error::InvalidExtension);
+        }
+        let payload = take_buffer_iter(bytes, len)?;
+        let array = Self(Cow::Owned(decode(&payload)?.into_owned()));
+        Ok((n + len, array))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn case<T>(values: Vec<T>)
+    where
+        T: TypedArrayElement + PartialEq + core::fmt::Debug,
+    {
+        let x = TypedArray::new(values.as_slice());
+        let mut bytes = vec![];
+        let n = x.pack(&mut bytes);
+        assert_eq!(n, bytes.len());
+        assert_eq!(n, x.packed_len());
+
+        let (o, y) = TypedArray::<T>::unpack(&bytes).unwrap();
+        let (p, z) = TypedArray::<T>::unpack_iter(bytes.iter().copied()).unwrap();
+        assert_eq!(n, o);
+        assert_eq!(n, p);
+        assert_eq!(x, y);
+        assert_eq!(x, z);
+
+        let ext = Extension::from(x.clone());
+        let mut ext_bytes = vec![];
+        ext.pack(&mut ext_bytes);
+        assert_eq!(bytes, ext_bytes);
+        assert_eq!(x, TypedArray::try_from(&ext).unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn typed_array_u8(v: Vec<u8>) {
+            case(v);
+        }
+
+        #[test]
+        fn typed_array_i16(v: Vec<i16>) {
+            case(v);
+        }
+
+        #[test]
+        fn typed_array_u32(v: Vec<u32>) {
+            case(v);
+        }
+
+        #[test]
+        fn typed_array_i64(v: Vec<i64>) {
+            case(v);
+        }
+
+        #[test]
+        fn typed_array_f32(v: Vec<f32>) {
+            case(v.into_iter().filter(|v| !v.is_nan()).collect());
+        }
+
+        #[test]
+        fn typed_array_f64(v: Vec<f64>) {
+            case(v.into_iter().filter(|v| !v.is_nan()).collect());
+        }
+    }
+
+    #[test]
+    fn typed_array_borrowed() {
+        let values = [1.5f64, -2.0, 1e300];
+        let mut bytes = vec![];
+        TypedArray::new(&values[..]).pack(&mut bytes);
+
+        // the payload starts after the 3 bytes of the header, and 2 bytes of kind and endianness
+        let mut aligned = vec![0u64; bytes.len() / 8 + 2];
+        let aligned: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(aligned.as_mut_ptr() as *mut u8, aligned.len() * 8)
+        };
+        aligned[3..3 + bytes.len()].copy_from_slice(&bytes);
+        let (n, x) = TypedArray::<f64>::unpack_borrowed(&aligned[3..]).unwrap();
+        assert_eq!(n, bytes.len());
+        assert_eq!(x.is_borrowed(), cfg!(target_endian = "little"));
+        assert_eq!(&values[..], &*x);
+
+        aligned[4..4 + bytes.len()].copy_from_slice(&bytes);
+        let (n, x) = TypedArray::<f64>::unpack_borrowed(&aligned[4..]).unwrap();
+        assert_eq!(n, bytes.len());
+        assert!(!x.is_borrowed());
+        assert_eq!(&values[..], &*x);
+    }
+
+    #[test]
+    fn typed_array_little_endian() {
+        let mut bytes = vec![];
+        TypedArray::new(&[0x01020304u32, 5][..]).pack(&mut bytes);
+        assert_eq!(
+            bytes,
+            [
+                0xc7,
+                10,
+                0x54,
+                u32::KIND,
+                LITTLE_ENDIAN,
+                4,
+                3,
+                2,
+                1,
+                5,
+                0,
+                0,
+                0
+            ]
+        );
+
+        let ext = Extension::from(TypedArray::new(&[0x0102i16][..]));
+        assert_eq!(
+            ext,
+            Extension::Ext(0x54, vec![i16::KIND, LITTLE_ENDIAN, 2, 1])
+        );
+    }
+
+    #[test]
+    fn typed_array_foreign_endianness() {
+        let endian = if NATIVE_ENDIAN == LITTLE_ENDIAN {
+            BIG_ENDIAN
+        } else {
+            LITTLE_ENDIAN
+        };
+        let mut payload = vec![u32::KIND, endian];
+        if endian == BIG_ENDIAN {
+            payload.extend(0x01020304u32.to_be_bytes());
+        } else {
+            payload.extend(0x01020304u32.to_le_bytes());
+        }
+        let ext = Extension::Ext(TypedArray::<u32>::TYPE, payload);
+        let x = TypedArray::<u32>::try_from(&ext).unwrap();
+        assert_eq!(&[0x01020304], &*x);
+    }
+
+    #[test]
+    fn typed_array_invalid() {
+        let ext = Extension::Ext(
+            TypedArray::<u32>::TYPE,
+            vec![u16::KIND, NATIVE_ENDIAN, 0, 0],
+        );
+        assert_eq!(
+            TypedArray::<u32>::try_from(&ext),
+            Err(Error::InvalidExtension)
+        );
+
+        let ext = Extension::Ext(
+            TypedArray::<u32>::TYPE,
+            vec![u32::KIND, NATIVE_ENDIAN, 0, 0],
+        );
+        assert_eq!(
+            TypedArray::<u32>::try_from(&ext),
+            Err(Error::InvalidExtension)
+        );
+
+        let ext = Extension::Ext(TypedArray::<u32>::TYPE, vec![u32::KIND, 2, 0, 0, 0, 0]);
+        assert_eq!(
+            TypedArray::<u32>::try_from(&ext),
+            Err(Error::InvalidExtension)
+        );
+
+        let ext = Extension::Ext(0, vec![u32::KIND, NATIVE_ENDIAN, 0, 0, 0, 0]);
+        let mut bytes = vec![];
+        ext.pack(&mut bytes);
+        assert_eq!(
+            TypedArray::<u32>::unpack(&bytes),
+            Err(Error::InvalidExtension)
+        );
+    }
+}
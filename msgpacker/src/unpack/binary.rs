@@ -34,17 +34,68 @@ pub fn unpack_str(mut buf: &[u8]) -> Result<(usize, &str), Error> {
     Ok((n + len, str))
 }
 
+/// Takes a binary header from the iterator, returning the amount of read bytes and the length.
+#[cfg(any(feature = "alloc", feature = "uuid"))]
+pub(super) fn take_bin_len_iter<I>(bytes: &mut I) -> Result<(usize, usize), Error>
+where
+    I: Iterator<Item = u8>,
+{
+    use super::helpers::{take_byte_iter, take_num_iter};
+
+    let format = take_byte_iter(bytes.by_ref())?;
+    match format {
+        Format::BIN8 => Ok((2, take_byte_iter(bytes)? as usize)),
+        Format::BIN16 => Ok((3, take_num_iter(bytes, u16::from_be_bytes)? as usize)),
+        Format::BIN32 => Ok((5, take_num_iter(bytes, u32::from_be_bytes)? as usize)),
+        _ => Err(Error::UnexpectedFormatTag),
+    }
+}
+
+/// Takes a string header from the iterator, returning the amount of read bytes and the length.
+#[cfg(any(feature = "alloc", feature = "arrayvec", feature = "heapless"))]
+pub(super) fn take_str_len_iter<I>(bytes: &mut I) -> Result<(usize, usize), Error>
+where
+    I: Iterator<Item = u8>,
+{
+    use super::helpers::{take_byte_iter, take_num_iter};
+
+    let format = take_byte_iter(bytes.by_ref())?;
+    match format {
+        0xa0..=0xbf => Ok((1, format as usize & 0x1f)),
+        Format::STR8 => Ok((2, take_byte_iter(bytes)? as usize)),
+        Format::STR16 => Ok((3, take_num_iter(bytes, u16::from_be_bytes)? as usize)),
+        Format::STR32 => Ok((5, take_num_iter(bytes, u32::from_be_bytes)? as usize)),
+        _ => Err(Error::UnexpectedFormatTag),
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use alloc::unpack_bytes_vec_iter;
+
 #[cfg(feature = "alloc")]
 mod alloc {
     use super::*;
-    use crate::helpers::{take_byte_iter, take_num_iter};
     use ::alloc::{string::String, vec::Vec};
 
-    impl Unpackable for Vec<u8> {
+    /// Unpacks binary into a vector; used by the vectors of `u8`.
+    pub fn unpack_bytes_vec_iter<I>(bytes: I) -> Result<(usize, Vec<u8>), Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter();
+        let (n, len) = take_bin_len_iter(&mut bytes)?;
+        let v: Vec<_> = bytes.take(len).collect();
+        if v.len() < len {
+            return Err(Error::BufferTooShort);
+        }
+        Ok((n + len, v))
+    }
+
+    impl Unpackable for String {
         type Error = Error;
 
         fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
-            unpack_bytes(buf).map(|(n, b)| (n, b.to_vec()))
+            unpack_str(buf).map(|(n, s)| (n, s.into()))
         }
 
         fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
@@ -52,32 +103,90 @@ mod alloc {
             I: IntoIterator<Item = u8>,
         {
             let mut bytes = bytes.into_iter();
-            let format = take_byte_iter(bytes.by_ref())?;
-            let (n, len) = match format {
-                Format::BIN8 => (2, take_byte_iter(bytes.by_ref())? as usize),
-                Format::BIN16 => (
-                    3,
-                    take_num_iter(bytes.by_ref(), u16::from_be_bytes)? as usize,
-                ),
-                Format::BIN32 => (
-                    5,
-                    take_num_iter(bytes.by_ref(), u32::from_be_bytes)? as usize,
-                ),
-                _ => return Err(Error::UnexpectedFormatTag),
-            };
+            let (n, len) = take_str_len_iter(&mut bytes)?;
             let v: Vec<_> = bytes.take(len).collect();
             if v.len() < len {
                 return Err(Error::BufferTooShort);
             }
-            Ok((n + len, v))
+            let s = String::from_utf8(v).map_err(|_| Error::InvalidUtf8)?;
+            Ok((n + len, s))
         }
     }
+}
 
-    impl Unpackable for String {
+#[cfg(feature = "bytes")]
+pub use shared::{unpack_bytes_shared, unpack_str_shared};
+
+#[cfg(feature = "bytes")]
+mod shared {
+    use super::*;
+    use ::alloc::vec::Vec;
+    use ::bytes::{Bytes, BytesMut};
+
+    /// Unpacks a binary from the buffer, returning the payload and the amount of read bytes.
+    ///
+    /// The payload is a reference counted slice of the buffer, so it isn't copied. Unpacking a
+    /// [Bytes] through [Unpackable], as derived types do, copies the payload instead.
+    pub fn unpack_bytes_shared(buf: &Bytes) -> Result<(usize, Bytes), Error> {
+        let (n, b) = unpack_bytes(buf)?;
+        Ok((n, buf.slice_ref(b)))
+    }
+
+    /// Unpacks a string from the buffer, returning the UTF-8 payload and the amount of read bytes.
+    ///
+    /// The payload is a reference counted slice of the buffer, so it isn't copied. Unpacking a
+    /// [Bytes] through [Unpackable], as derived types do, copies the payload instead.
+    pub fn unpack_str_shared(buf: &Bytes) -> Result<(usize, Bytes), Error> {
+        let (n, s) = unpack_str(buf)?;
+        Ok((n, buf.slice_ref(s.as_bytes())))
+    }
+
+    // The trait only sees a borrowed slice, so the payload is copied; use `unpack_bytes_shared` to
+    // share the buffer instead.
+    impl Unpackable for Bytes {
         type Error = Error;
 
         fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
-            unpack_str(buf).map(|(n, s)| (n, s.into()))
+            unpack_bytes(buf).map(|(n, b)| (n, Bytes::copy_from_slice(b)))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            Vec::<u8>::unpack_iter(bytes).map(|(n, b)| (n, b.into()))
+        }
+    }
+
+    impl Unpackable for BytesMut {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_bytes(buf).map(|(n, b)| (n, b.into()))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            Vec::<u8>::unpack_iter(bytes).map(|(n, b)| (n, b.as_slice().into()))
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid {
+    use super::*;
+    use crate::helpers::take_num_iter;
+    use ::uuid::Uuid;
+
+    impl Unpackable for Uuid {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            let (n, bytes) = unpack_bytes(buf)?;
+            let uuid = Uuid::from_slice(bytes).map_err(|_| Error::UnexpectedBinLength)?;
+            Ok((n, uuid))
         }
 
         fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
@@ -85,26 +194,38 @@ mod alloc {
             I: IntoIterator<Item = u8>,
         {
             let mut bytes = bytes.into_iter();
-            let format = take_byte_iter(bytes.by_ref())?;
-            let (n, len) = match format {
-                0xa0..=0xbf => (1, format as usize & 0x1f),
-                Format::STR8 => (2, take_byte_iter(bytes.by_ref())? as usize),
-                Format::STR16 => (
-                    3,
-                    take_num_iter(bytes.by_ref(), u16::from_be_bytes)? as usize,
-                ),
-                Format::STR32 => (
-                    5,
-                    take_num_iter(bytes.by_ref(), u32::from_be_bytes)? as usize,
-                ),
-                _ => return Err(Error::UnexpectedFormatTag),
-            };
-            let v: Vec<_> = bytes.take(len).collect();
-            if v.len() < len {
-                return Err(Error::BufferTooShort);
+            let (n, len) = take_bin_len_iter(&mut bytes)?;
+            if len != 16 {
+                return Err(Error::UnexpectedBinLength);
             }
-            let s = String::from_utf8(v).map_err(|_| Error::InvalidUtf8)?;
-            Ok((n + len, s))
+            let uuid = take_num_iter(bytes, Uuid::from_bytes)?;
+            Ok((n + len, uuid))
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    use super::*;
+    use ::alloc::string::String;
+    use ::rust_decimal::Decimal;
+
+    impl Unpackable for Decimal {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            let (n, s) = unpack_str(buf)?;
+            let d = Decimal::from_str_exact(s).map_err(|_| Error::InvalidDecimal)?;
+            Ok((n, d))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let (n, s) = String::unpack_iter(bytes)?;
+            let d = Decimal::from_str_exact(&s).map_err(|_| Error::InvalidDecimal)?;
+            Ok((n, d))
         }
     }
 }
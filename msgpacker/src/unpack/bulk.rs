@@ -0,0 +1,77 @@
+use super::{
+    helpers::{take_byte, take_num},
+    Error, Format, Unpackable,
+};
+use alloc::vec::Vec;
+
+/// Unpacks an array into a vector, decoding the elements encoded with the fixed-width `format` in a
+/// tight loop and falling back to [Unpackable::unpack] for the others.
+fn unpack_vec<X, const N: usize>(
+    mut buf: &[u8],
+    format: u8,
+    from_be_bytes: fn([u8; N]) -> X,
+) -> Result<(usize, Vec<X>), Error>
+where
+    X: Unpackable<Error = Error>,
+{
+    let tag = take_byte(&mut buf)?;
+    let (mut n, len) = match tag {
+        0x90..=0x9f => (1, (tag & 0x0f) as usize),
+        Format::ARRAY16 => (
+            3,
+            take_num(&mut buf, u16::from_be_bytes).map(|v| v as usize)?,
+        ),
+        Format::ARRAY32 => (
+            5,
+            take_num(&mut buf, u32::from_be_bytes).map(|v| v as usize)?,
+        ),
+        _ => return Err(Error::UnexpectedFormatTag),
+    };
+
+    // every element takes at least one byte
+    let mut values = Vec::with_capacity(len.min(buf.len()));
+    for c in buf.chunks_exact(N + 1).take(len) {
+        if c[0] != format {
+            break;
+        }
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&c[1..]);
+        values.push(from_be_bytes(bytes));
+    }
+    let count = values.len() * (N + 1);
+    buf = &buf[count..];
+    n += count;
+
+    while values.len() < len {
+        let (count, v) = X::unpack(buf)?;
+        buf = &buf[count..];
+        n += count;
+        values.push(v);
+    }
+
+    Ok((n, values))
+}
+
+macro_rules! unpack_vec {
+    ($f:ident, $t:ty, $format:expr, $n:expr) => {
+        #[doc = concat!(
+            "Unpacks an array of `", stringify!($t), "` from the buffer, returning the vector and the ",
+            "amount of read bytes.\n\n",
+            "Elements encoded with the fixed-width `", stringify!($format), "` format, as produced by ",
+            "the slice packing functions, are decoded in a tight loop. Any other valid encoding is ",
+            "accepted as well."
+        )]
+        pub fn $f(buf: &[u8]) -> Result<(usize, Vec<$t>), Error> {
+            unpack_vec::<$t, $n>(buf, $format, <$t>::from_be_bytes)
+        }
+    };
+}
+
+unpack_vec!(unpack_u16_vec, u16, Format::UINT16, 2);
+unpack_vec!(unpack_u32_vec, u32, Format::UINT32, 4);
+unpack_vec!(unpack_u64_vec, u64, Format::UINT64, 8);
+unpack_vec!(unpack_i16_vec, i16, Format::INT16, 2);
+unpack_vec!(unpack_i32_vec, i32, Format::INT32, 4);
+unpack_vec!(unpack_i64_vec, i64, Format::INT64, 8);
+unpack_vec!(unpack_f32_vec, f32, Format::FLOAT32, 4);
+unpack_vec!(unpack_f64_vec, f64, Format::FLOAT64, 8);
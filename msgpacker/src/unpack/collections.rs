@@ -3,25 +3,70 @@ use super::{
     Error, Format, Unpackable,
 };
 
-/// Unpacks an array from the buffer, returning a collectable type and the amount of read bytes.
-pub fn unpack_array<V, C>(mut buf: &[u8]) -> Result<(usize, C), <V as Unpackable>::Error>
+/// Takes an array header from the buffer, returning the amount of read bytes and the length.
+fn take_array_len(buf: &mut &[u8]) -> Result<(usize, usize), Error> {
+    let format = take_byte(buf)?;
+    match format {
+        0x90..=0x9f => Ok((1, (format & 0x0f) as usize)),
+        Format::ARRAY16 => Ok((3, take_num(buf, u16::from_be_bytes)? as usize)),
+        Format::ARRAY32 => Ok((5, take_num(buf, u32::from_be_bytes)? as usize)),
+        _ => Err(Error::UnexpectedFormatTag),
+    }
+}
+
+/// Takes an array header from the iterator, returning the amount of read bytes and the length.
+fn take_array_len_iter<I>(bytes: &mut I) -> Result<(usize, usize), Error>
+where
+    I: Iterator<Item = u8>,
+{
+    let format = take_byte_iter(bytes.by_ref())?;
+    match format {
+        0x90..=0x9f => Ok((1, (format & 0x0f) as usize)),
+        Format::ARRAY16 => Ok((3, take_num_iter(bytes, u16::from_be_bytes)? as usize)),
+        Format::ARRAY32 => Ok((5, take_num_iter(bytes, u32::from_be_bytes)? as usize)),
+        _ => Err(Error::UnexpectedFormatTag),
+    }
+}
+
+/// Takes a map header from the buffer, returning the amount of read bytes and the length.
+fn take_map_len(buf: &mut &[u8]) -> Result<(usize, usize), Error> {
+    let format = take_byte(buf)?;
+    match format {
+        0x80..=0x8f => Ok((1, (format & 0x0f) as usize)),
+        Format::MAP16 => Ok((3, take_num(buf, u16::from_be_bytes)? as usize)),
+        Format::MAP32 => Ok((5, take_num(buf, u32::from_be_bytes)? as usize)),
+        _ => Err(Error::UnexpectedFormatTag),
+    }
+}
+
+/// Takes a map header from the iterator, returning the amount of read bytes and the length.
+fn take_map_len_iter<I>(bytes: &mut I) -> Result<(usize, usize), Error>
+where
+    I: Iterator<Item = u8>,
+{
+    let format = take_byte_iter(bytes.by_ref())?;
+    match format {
+        0x80..=0x8f => Ok((1, (format & 0x0f) as usize)),
+        Format::MAP16 => Ok((3, take_num_iter(bytes, u16::from_be_bytes)? as usize)),
+        Format::MAP32 => Ok((5, take_num_iter(bytes, u32::from_be_bytes)? as usize)),
+        _ => Err(Error::UnexpectedFormatTag),
+    }
+}
+
+/// Unpacks an array of at most `capacity` elements from the buffer, failing with
+/// [Error::CapacityOverflow] before unpacking any element of a longer array.
+fn unpack_array_capped<V, C>(
+    mut buf: &[u8],
+    capacity: usize,
+) -> Result<(usize, C), <V as Unpackable>::Error>
 where
     V: Unpackable,
     C: FromIterator<V>,
 {
-    let format = take_byte(&mut buf)?;
-    let (mut n, len) = match format {
-        0x90..=0x9f => (1, (format & 0x0f) as usize),
-        Format::ARRAY16 => (
-            3,
-            take_num(&mut buf, u16::from_be_bytes).map(|v| v as usize)?,
-        ),
-        Format::ARRAY32 => (
-            5,
-            take_num(&mut buf, u32::from_be_bytes).map(|v| v as usize)?,
-        ),
-        _ => return Err(Error::UnexpectedFormatTag.into()),
-    };
+    let (mut n, len) = take_array_len(&mut buf)?;
+    if len > capacity {
+        return Err(Error::CapacityOverflow.into());
+    }
     let array: C = (0..len)
         .map(|_| {
             let (count, v) = V::unpack(buf)?;
@@ -33,27 +78,22 @@ where
     Ok((n, array))
 }
 
-/// Unpacks an array from the iterator, returning a collectable type and the amount of read bytes.
-pub fn unpack_array_iter<I, V, C>(iter: I) -> Result<(usize, C), <V as Unpackable>::Error>
+/// Unpacks an array of at most `capacity` elements from the iterator, failing with
+/// [Error::CapacityOverflow] before unpacking any element of a longer array.
+fn unpack_array_capped_iter<I, V, C>(
+    iter: I,
+    capacity: usize,
+) -> Result<(usize, C), <V as Unpackable>::Error>
 where
     I: IntoIterator<Item = u8>,
     V: Unpackable,
     C: FromIterator<V>,
 {
     let mut bytes = iter.into_iter();
-    let format = take_byte_iter(bytes.by_ref())?;
-    let (mut n, len) = match format {
-        0x90..=0x9f => (1, (format & 0x0f) as usize),
-        Format::ARRAY16 => (
-            3,
-            take_num_iter(bytes.by_ref(), u16::from_be_bytes).map(|v| v as usize)?,
-        ),
-        Format::ARRAY32 => (
-            5,
-            take_num_iter(bytes.by_ref(), u32::from_be_bytes).map(|v| v as usize)?,
-        ),
-        _ => return Err(Error::UnexpectedFormatTag.into()),
-    };
+    let (mut n, len) = take_array_len_iter(&mut bytes)?;
+    if len > capacity {
+        return Err(Error::CapacityOverflow.into());
+    }
     let array: C = (0..len)
         .map(|_| {
             let (count, v) = V::unpack_iter(bytes.by_ref())?;
@@ -64,27 +104,41 @@ where
     Ok((n, array))
 }
 
-/// Unpacks a map from the buffer, returning a collectable type and the amount of read bytes.
-pub fn unpack_map<K, V, C>(mut buf: &[u8]) -> Result<(usize, C), <V as Unpackable>::Error>
+/// Unpacks an array from the buffer, returning a collectable type and the amount of read bytes.
+pub fn unpack_array<V, C>(buf: &[u8]) -> Result<(usize, C), <V as Unpackable>::Error>
+where
+    V: Unpackable,
+    C: FromIterator<V>,
+{
+    unpack_array_capped(buf, usize::MAX)
+}
+
+/// Unpacks an array from the iterator, returning a collectable type and the amount of read bytes.
+pub fn unpack_array_iter<I, V, C>(iter: I) -> Result<(usize, C), <V as Unpackable>::Error>
+where
+    I: IntoIterator<Item = u8>,
+    V: Unpackable,
+    C: FromIterator<V>,
+{
+    unpack_array_capped_iter(iter, usize::MAX)
+}
+
+/// Unpacks a map of at most `capacity` entries from the buffer, returning the amount of read bytes,
+/// the amount of entries and the collected map.
+fn unpack_map_entries<K, V, C>(
+    mut buf: &[u8],
+    capacity: usize,
+) -> Result<(usize, usize, C), <V as Unpackable>::Error>
 where
     K: Unpackable,
     V: Unpackable,
     <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
     C: FromIterator<(K, V)>,
 {
-    let format = take_byte(&mut buf)?;
-    let (mut n, len) = match format {
-        0x80..=0x8f => (1, (format & 0x0f) as usize),
-        Format::MAP16 => (
-            3,
-            take_num(&mut buf, u16::from_be_bytes).map(|v| v as usize)?,
-        ),
-        Format::MAP32 => (
-            5,
-            take_num(&mut buf, u32::from_be_bytes).map(|v| v as usize)?,
-        ),
-        _ => return Err(Error::UnexpectedFormatTag.into()),
-    };
+    let (mut n, len) = take_map_len(&mut buf)?;
+    if len > capacity {
+        return Err(Error::CapacityOverflow.into());
+    }
     let map: C = (0..len)
         .map(|_| {
             let (count, k) = K::unpack(buf)?;
@@ -96,11 +150,15 @@ where
             Ok((k, v))
         })
         .collect::<Result<_, <V as Unpackable>::Error>>()?;
-    Ok((n, map))
+    Ok((n, len, map))
 }
 
-/// Unpacks a map from the iterator, returning a collectable type and the amount of read bytes.
-pub fn unpack_map_iter<I, K, V, C>(iter: I) -> Result<(usize, C), <V as Unpackable>::Error>
+/// Unpacks a map of at most `capacity` entries from the iterator, returning the amount of read
+/// bytes, the amount of entries and the collected map.
+fn unpack_map_entries_iter<I, K, V, C>(
+    iter: I,
+    capacity: usize,
+) -> Result<(usize, usize, C), <V as Unpackable>::Error>
 where
     I: IntoIterator<Item = u8>,
     K: Unpackable,
@@ -109,19 +167,10 @@ where
     C: FromIterator<(K, V)>,
 {
     let mut bytes = iter.into_iter();
-    let format = take_byte_iter(bytes.by_ref())?;
-    let (mut n, len) = match format {
-        0x80..=0x8f => (1, (format & 0x0f) as usize),
-        Format::MAP16 => (
-            3,
-            take_num_iter(bytes.by_ref(), u16::from_be_bytes).map(|v| v as usize)?,
-        ),
-        Format::MAP32 => (
-            5,
-            take_num_iter(bytes.by_ref(), u32::from_be_bytes).map(|v| v as usize)?,
-        ),
-        _ => return Err(Error::UnexpectedFormatTag.into()),
-    };
+    let (mut n, len) = take_map_len_iter(&mut bytes)?;
+    if len > capacity {
+        return Err(Error::CapacityOverflow.into());
+    }
     let map: C = (0..len)
         .map(|_| {
             let (count, k) = K::unpack_iter(bytes.by_ref())?;
@@ -131,13 +180,111 @@ where
             Ok((k, v))
         })
         .collect::<Result<_, <V as Unpackable>::Error>>()?;
-    Ok((n, map))
+    Ok((n, len, map))
+}
+
+/// Unpacks a map from the buffer, returning a collectable type and the amount of read bytes.
+///
+/// If the map contains duplicated keys, the behavior is defined by the collection; a `HashMap`, for
+/// instance, will keep the last value. Use [unpack_map_unique] to reject such maps.
+pub fn unpack_map<K, V, C>(buf: &[u8]) -> Result<(usize, C), <V as Unpackable>::Error>
+where
+    K: Unpackable,
+    V: Unpackable,
+    <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+    C: FromIterator<(K, V)>,
+{
+    unpack_map_entries(buf, usize::MAX).map(|(n, _, map)| (n, map))
+}
+
+/// Unpacks a map from the iterator, returning a collectable type and the amount of read bytes.
+///
+/// If the map contains duplicated keys, the behavior is defined by the collection; a `HashMap`, for
+/// instance, will keep the last value. Use [unpack_map_unique_iter] to reject such maps.
+pub fn unpack_map_iter<I, K, V, C>(iter: I) -> Result<(usize, C), <V as Unpackable>::Error>
+where
+    I: IntoIterator<Item = u8>,
+    K: Unpackable,
+    V: Unpackable,
+    <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+    C: FromIterator<(K, V)>,
+{
+    unpack_map_entries_iter(iter, usize::MAX).map(|(n, _, map)| (n, map))
 }
 
+#[cfg(feature = "alloc")]
+pub use alloc::{unpack_map_unique, unpack_map_unique_iter};
+
 #[cfg(feature = "alloc")]
 mod alloc {
     use super::*;
     use ::alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+    use ::alloc::vec::Vec;
+
+    /// Collects the entries of a map, failing if any of their keys is duplicated.
+    fn collect_unique<K, V, C>(entries: Vec<(K, V)>) -> Result<C, Error>
+    where
+        K: Ord,
+        C: FromIterator<(K, V)>,
+    {
+        let mut keys: Vec<&K> = entries.iter().map(|(k, _)| k).collect();
+        keys.sort_unstable();
+        if keys.windows(2).any(|w| w[0] == w[1]) {
+            return Err(Error::DuplicateMapKey);
+        }
+        Ok(entries.into_iter().collect())
+    }
+
+    /// Unpacks a map from the buffer, returning a collectable type and the amount of read bytes.
+    ///
+    /// Fails with [Error::DuplicateMapKey] if any key of the map is duplicated, regardless of how
+    /// the collection handles duplicated keys.
+    pub fn unpack_map_unique<K, V, C>(buf: &[u8]) -> Result<(usize, C), <V as Unpackable>::Error>
+    where
+        K: Unpackable + Ord,
+        V: Unpackable,
+        <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+        C: FromIterator<(K, V)>,
+    {
+        let (n, entries) = unpack_map::<K, V, Vec<(K, V)>>(buf)?;
+        Ok((n, collect_unique(entries)?))
+    }
+
+    /// Unpacks a map from the iterator, returning a collectable type and the amount of read bytes.
+    ///
+    /// Fails with [Error::DuplicateMapKey] if any key of the map is duplicated, regardless of how
+    /// the collection handles duplicated keys.
+    pub fn unpack_map_unique_iter<I, K, V, C>(
+        iter: I,
+    ) -> Result<(usize, C), <V as Unpackable>::Error>
+    where
+        I: IntoIterator<Item = u8>,
+        K: Unpackable + Ord,
+        V: Unpackable,
+        <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+        C: FromIterator<(K, V)>,
+    {
+        let (n, entries) = unpack_map_iter::<I, K, V, Vec<(K, V)>>(iter)?;
+        Ok((n, collect_unique(entries)?))
+    }
+
+    impl<X> Unpackable for Vec<X>
+    where
+        X: Unpackable,
+    {
+        type Error = <X as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            X::unpack_vec(buf)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            X::unpack_vec_iter(bytes)
+        }
+    }
 
     impl<X> Unpackable for BTreeSet<X>
     where
@@ -278,3 +425,307 @@ mod std {
         }
     }
 }
+
+#[cfg(feature = "indexmap")]
+mod indexmap {
+    use super::*;
+    use ::indexmap::{IndexMap, IndexSet};
+    use core::hash::{BuildHasher, Hash};
+
+    impl<X, S> Unpackable for IndexSet<X, S>
+    where
+        X: Unpackable + Hash + Eq,
+        S: BuildHasher + Default,
+    {
+        type Error = <X as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_array(buf)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_array_iter(bytes)
+        }
+    }
+
+    impl<K, V, S> Unpackable for IndexMap<K, V, S>
+    where
+        K: Unpackable + Hash + Eq,
+        V: Unpackable,
+        <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+        S: BuildHasher + Default,
+    {
+        type Error = <V as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_map(buf)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_map_iter(bytes)
+        }
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+mod hashbrown {
+    use super::*;
+    use ::hashbrown::{HashMap, HashSet};
+    use core::hash::{BuildHasher, Hash};
+
+    impl<X, S> Unpackable for HashSet<X, S>
+    where
+        X: Unpackable + Hash + Eq,
+        S: BuildHasher + Default,
+    {
+        type Error = <X as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_array(buf)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_array_iter(bytes)
+        }
+    }
+
+    impl<K, V, S> Unpackable for HashMap<K, V, S>
+    where
+        K: Unpackable + Hash + Eq,
+        V: Unpackable,
+        <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+        S: BuildHasher + Default,
+    {
+        type Error = <V as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_map(buf)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_map_iter(bytes)
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec {
+    use super::*;
+    use ::smallvec::{Array, SmallVec};
+
+    fn is_bin(format: u8) -> bool {
+        matches!(format, Format::BIN8 | Format::BIN16 | Format::BIN32)
+    }
+
+    impl<A> Unpackable for SmallVec<A>
+    where
+        A: Array,
+        A::Item: Unpackable,
+    {
+        type Error = <A::Item as Unpackable>::Error;
+
+        // Only bytes are packed as binary; arrays are collected in place so the elements that fit
+        // inline don't allocate.
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            match buf.first() {
+                Some(f) if is_bin(*f) => {
+                    A::Item::unpack_vec(buf).map(|(n, v)| (n, SmallVec::from_vec(v)))
+                }
+                _ => unpack_array(buf),
+            }
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let mut bytes = bytes.into_iter().peekable();
+            match bytes.peek() {
+                Some(f) if is_bin(*f) => {
+                    A::Item::unpack_vec_iter(bytes).map(|(n, v)| (n, SmallVec::from_vec(v)))
+                }
+                _ => unpack_array_iter(bytes),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec {
+    use super::*;
+    use crate::unpack::binary::{take_str_len_iter, unpack_str};
+    use ::arrayvec::{ArrayString, ArrayVec};
+    use core::str;
+
+    impl<X, const CAP: usize> Unpackable for ArrayVec<X, CAP>
+    where
+        X: Unpackable,
+    {
+        type Error = <X as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_array_capped(buf, CAP)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_array_capped_iter(bytes, CAP)
+        }
+    }
+
+    impl<const CAP: usize> Unpackable for ArrayString<CAP> {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            let (n, s) = unpack_str(buf)?;
+            let s = ArrayString::from(s).map_err(|_| Error::CapacityOverflow)?;
+            Ok((n, s))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let mut bytes = bytes.into_iter();
+            let (n, len) = take_str_len_iter(&mut bytes)?;
+            if len > CAP {
+                return Err(Error::CapacityOverflow);
+            }
+            let v: ArrayVec<u8, CAP> = bytes.take(len).collect();
+            if v.len() < len {
+                return Err(Error::BufferTooShort);
+            }
+            let s = str::from_utf8(&v).map_err(|_| Error::InvalidUtf8)?;
+            let s = ArrayString::from(s).map_err(|_| Error::CapacityOverflow)?;
+            Ok((n + len, s))
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless {
+    use super::*;
+    use crate::unpack::binary::{take_str_len_iter, unpack_str};
+    use ::heapless::{IndexMap, IndexSet, LinearMap, String, Vec};
+    use core::hash::{BuildHasher, Hash};
+
+    impl<X, const N: usize> Unpackable for Vec<X, N>
+    where
+        X: Unpackable,
+    {
+        type Error = <X as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_array_capped(buf, N)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_array_capped_iter(bytes, N)
+        }
+    }
+
+    impl<const N: usize> Unpackable for String<N> {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            let (n, s) = unpack_str(buf)?;
+            let mut string = String::new();
+            string.push_str(s).map_err(|_| Error::CapacityOverflow)?;
+            Ok((n, string))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let mut bytes = bytes.into_iter();
+            let (n, len) = take_str_len_iter(&mut bytes)?;
+            if len > N {
+                return Err(Error::CapacityOverflow);
+            }
+            let v: Vec<u8, N> = bytes.take(len).collect();
+            if v.len() < len {
+                return Err(Error::BufferTooShort);
+            }
+            let s = String::from_utf8(v).map_err(|_| Error::InvalidUtf8)?;
+            Ok((n + len, s))
+        }
+    }
+
+    impl<X, S, const N: usize> Unpackable for IndexSet<X, S, N>
+    where
+        X: Unpackable + Hash + Eq,
+        S: BuildHasher + Default,
+    {
+        type Error = <X as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_array_capped(buf, N)
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_array_capped_iter(bytes, N)
+        }
+    }
+
+    impl<K, V, S, const N: usize> Unpackable for IndexMap<K, V, S, N>
+    where
+        K: Unpackable + Hash + Eq,
+        V: Unpackable,
+        <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+        S: BuildHasher + Default,
+    {
+        type Error = <V as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_map_entries(buf, N).map(|(n, _, map)| (n, map))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_map_entries_iter(bytes, N).map(|(n, _, map)| (n, map))
+        }
+    }
+
+    impl<K, V, const N: usize> Unpackable for LinearMap<K, V, N>
+    where
+        K: Unpackable + Eq,
+        V: Unpackable,
+        <V as Unpackable>::Error: From<<K as Unpackable>::Error>,
+    {
+        type Error = <V as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            unpack_map_entries(buf, N).map(|(n, _, map)| (n, map))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            unpack_map_entries_iter(bytes, N).map(|(n, _, map)| (n, map))
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use super::{
-    helpers::{take_byte, take_byte_iter},
+    helpers::{take_byte, take_byte_iter, ArrayGuard},
     Error, Format, Unpackable,
 };
-use core::{marker::PhantomData, mem::MaybeUninit};
+use core::{cmp::Ordering, marker::PhantomData, time::Duration};
 
 impl Unpackable for () {
     type Error = Error;
@@ -58,6 +58,98 @@ impl Unpackable for bool {
     }
 }
 
+#[cfg(target_has_atomic = "8")]
+impl Unpackable for core::sync::atomic::AtomicBool {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        bool::unpack(buf).map(|(n, v)| (n, Self::new(v)))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        bool::unpack_iter(bytes).map(|(n, v)| (n, Self::new(v)))
+    }
+}
+
+impl Unpackable for char {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        let (n, v) = u32::unpack(buf)?;
+        char::from_u32(v).map(|c| (n, c)).ok_or(Error::InvalidChar)
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let (n, v) = u32::unpack_iter(bytes)?;
+        char::from_u32(v).map(|c| (n, c)).ok_or(Error::InvalidChar)
+    }
+}
+
+fn ordering(v: i8) -> Result<Ordering, Error> {
+    match v {
+        -1 => Ok(Ordering::Less),
+        0 => Ok(Ordering::Equal),
+        1 => Ok(Ordering::Greater),
+        _ => Err(Error::InvalidEnumVariant),
+    }
+}
+
+impl Unpackable for Ordering {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        let (n, v) = i8::unpack(buf)?;
+        Ok((n, ordering(v)?))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let (n, v) = i8::unpack_iter(bytes)?;
+        Ok((n, ordering(v)?))
+    }
+}
+
+fn duration(secs: u64, nanos: u32) -> Result<Duration, Error> {
+    if nanos >= 1_000_000_000 {
+        return Err(Error::InvalidDuration);
+    }
+    Ok(Duration::new(secs, nanos))
+}
+
+impl Unpackable for Duration {
+    type Error = Error;
+
+    fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        if take_byte(&mut buf)? != 0x92 {
+            return Err(Error::UnexpectedFormatTag);
+        }
+        let (n, secs) = u64::unpack(buf)?;
+        let (m, nanos) = u32::unpack(&buf[n..])?;
+        Ok((1 + n + m, duration(secs, nanos)?))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter();
+        if take_byte_iter(bytes.by_ref())? != 0x92 {
+            return Err(Error::UnexpectedFormatTag);
+        }
+        let (n, secs) = u64::unpack_iter(bytes.by_ref())?;
+        let (m, nanos) = u32::unpack_iter(bytes)?;
+        Ok((1 + n + m, duration(secs, nanos)?))
+    }
+}
+
 impl<X> Unpackable for Option<X>
 where
     X: Unpackable,
@@ -88,6 +180,38 @@ where
     }
 }
 
+impl<X, E> Unpackable for Result<X, E>
+where
+    X: Unpackable,
+    E: Unpackable,
+    <X as Unpackable>::Error: From<<E as Unpackable>::Error>,
+{
+    type Error = <X as Unpackable>::Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        let (n, discriminant) = u32::unpack(buf)?;
+        let buf = &buf[n..];
+        match discriminant {
+            0 => X::unpack(buf).map(|(m, x)| (n + m, Ok(x))),
+            1 => Ok(E::unpack(buf).map(|(m, e)| (n + m, Err(e)))?),
+            _ => Err(Error::InvalidEnumVariant.into()),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter();
+        let (n, discriminant) = u32::unpack_iter(bytes.by_ref())?;
+        match discriminant {
+            0 => X::unpack_iter(bytes).map(|(m, x)| (n + m, Ok(x))),
+            1 => Ok(E::unpack_iter(bytes).map(|(m, e)| (n + m, Err(e)))?),
+            _ => Err(Error::InvalidEnumVariant.into()),
+        }
+    }
+}
+
 macro_rules! array {
     ($n:expr) => {
         impl<X> Unpackable for [X; $n]
@@ -96,39 +220,33 @@ macro_rules! array {
         {
             type Error = <X as Unpackable>::Error;
 
+            #[allow(clippy::reversed_empty_ranges)]
             fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
-                let mut array = MaybeUninit::uninit_array();
-                let n =
-                    array
-                        .iter_mut()
-                        .try_fold::<_, _, Result<_, Self::Error>>(0, |count, a| {
-                            let (n, x) = X::unpack(buf)?;
-                            buf = &buf[n..];
-                            a.write(x);
-                            Ok(count + n)
-                        })?;
-                // Safety: array is initialized
-                let array = unsafe { MaybeUninit::array_assume_init(array) };
-                Ok((n, array))
+                let mut array = ArrayGuard::new();
+                let mut n = 0;
+                for _ in 0..$n {
+                    let (count, x) = X::unpack(buf)?;
+                    buf = &buf[count..];
+                    array.push(x);
+                    n += count;
+                }
+                Ok((n, array.into_array()))
             }
 
+            #[allow(clippy::reversed_empty_ranges)]
             fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
             where
                 I: IntoIterator<Item = u8>,
             {
                 let mut bytes = bytes.into_iter();
-                let mut array = MaybeUninit::uninit_array();
-                let n =
-                    array
-                        .iter_mut()
-                        .try_fold::<_, _, Result<_, Self::Error>>(0, |count, a| {
-                            let (n, x) = X::unpack_iter(bytes.by_ref())?;
-                            a.write(x);
-                            Ok(count + n)
-                        })?;
-                // Safety: array is initialized
-                let array = unsafe { MaybeUninit::array_assume_init(array) };
-                Ok((n, array))
+                let mut array = ArrayGuard::new();
+                let mut n = 0;
+                for _ in 0..$n {
+                    let (count, x) = X::unpack_iter(bytes.by_ref())?;
+                    array.push(x);
+                    n += count;
+                }
+                Ok((n, array.into_array()))
             }
         }
     };
@@ -52,3 +52,40 @@ impl Unpackable for f64 {
         }
     }
 }
+
+#[cfg(feature = "half")]
+mod half {
+    use super::*;
+    use ::half::{bf16, f16};
+
+    // Unpacked from `FLOAT32`, rounding to the nearest representable value.
+    impl Unpackable for f16 {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            f32::unpack(buf).map(|(n, x)| (n, f16::from_f32(x)))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            f32::unpack_iter(bytes).map(|(n, x)| (n, f16::from_f32(x)))
+        }
+    }
+
+    impl Unpackable for bf16 {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            f32::unpack(buf).map(|(n, x)| (n, bf16::from_f32(x)))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            f32::unpack_iter(bytes).map(|(n, x)| (n, bf16::from_f32(x)))
+        }
+    }
+}
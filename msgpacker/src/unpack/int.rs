@@ -2,6 +2,18 @@ use super::{
     helpers::{take_byte, take_byte_iter, take_num, take_num_iter},
     Error, Format, Unpackable,
 };
+use core::{
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+    },
+    sync::atomic,
+};
+
+#[cfg(feature = "alloc")]
+use super::binary::{unpack_bytes, unpack_bytes_vec_iter};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 impl Unpackable for u8 {
     type Error = Error;
@@ -27,6 +39,19 @@ impl Unpackable for u8 {
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn unpack_vec(buf: &[u8]) -> Result<(usize, Vec<Self>), Self::Error> {
+        unpack_bytes(buf).map(|(n, b)| (n, b.to_vec()))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn unpack_vec_iter<I>(bytes: I) -> Result<(usize, Vec<Self>), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        unpack_bytes_vec_iter(bytes)
+    }
 }
 
 impl Unpackable for u16 {
@@ -204,6 +229,11 @@ impl Unpackable for i8 {
             0x00..=Format::POSITIVE_FIXINT => Ok((1, format as i8)),
             0xe0.. => Ok((1, format as i8)),
             Format::INT8 => take_byte(&mut buf).map(|v| (2, v as i8)),
+            Format::UINT8 => take_byte(&mut buf).and_then(|v| {
+                i8::try_from(v)
+                    .map(|v| (2, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -218,6 +248,11 @@ impl Unpackable for i8 {
             0x00..=Format::POSITIVE_FIXINT => Ok((1, format as i8)),
             0xe0.. => Ok((1, format as i8)),
             Format::INT8 => take_byte_iter(bytes).map(|v| (2, v as i8)),
+            Format::UINT8 => take_byte_iter(bytes).and_then(|v| {
+                i8::try_from(v)
+                    .map(|v| (2, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -233,6 +268,12 @@ impl Unpackable for i16 {
             0xe0.. => Ok((1, (format as i8) as i16)),
             Format::INT8 => take_byte(&mut buf).map(|v| (2, v as i8 as i16)),
             Format::INT16 => take_num(&mut buf, i16::from_be_bytes).map(|v| (3, v)),
+            Format::UINT8 => take_byte(&mut buf).map(|v| (2, v as i16)),
+            Format::UINT16 => take_num(&mut buf, u16::from_be_bytes).and_then(|v| {
+                i16::try_from(v)
+                    .map(|v| (3, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -248,6 +289,12 @@ impl Unpackable for i16 {
             0xe0.. => Ok((1, (format as i8) as i16)),
             Format::INT8 => take_byte_iter(bytes).map(|v| (2, v as i8 as i16)),
             Format::INT16 => take_num_iter(bytes, i16::from_be_bytes).map(|v| (3, v)),
+            Format::UINT8 => take_byte_iter(bytes).map(|v| (2, v as i16)),
+            Format::UINT16 => take_num_iter(bytes, u16::from_be_bytes).and_then(|v| {
+                i16::try_from(v)
+                    .map(|v| (3, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -264,6 +311,13 @@ impl Unpackable for i32 {
             Format::INT8 => take_byte(&mut buf).map(|v| (2, v as i8 as i32)),
             Format::INT16 => take_num(&mut buf, i16::from_be_bytes).map(|v| (3, v as i32)),
             Format::INT32 => take_num(&mut buf, i32::from_be_bytes).map(|v| (5, v)),
+            Format::UINT8 => take_byte(&mut buf).map(|v| (2, v as i32)),
+            Format::UINT16 => take_num(&mut buf, u16::from_be_bytes).map(|v| (3, v as i32)),
+            Format::UINT32 => take_num(&mut buf, u32::from_be_bytes).and_then(|v| {
+                i32::try_from(v)
+                    .map(|v| (5, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -280,6 +334,13 @@ impl Unpackable for i32 {
             Format::INT8 => take_byte_iter(bytes).map(|v| (2, v as i8 as i32)),
             Format::INT16 => take_num_iter(bytes, i16::from_be_bytes).map(|v| (3, v as i32)),
             Format::INT32 => take_num_iter(bytes, i32::from_be_bytes).map(|v| (5, v)),
+            Format::UINT8 => take_byte_iter(bytes).map(|v| (2, v as i32)),
+            Format::UINT16 => take_num_iter(bytes, u16::from_be_bytes).map(|v| (3, v as i32)),
+            Format::UINT32 => take_num_iter(bytes, u32::from_be_bytes).and_then(|v| {
+                i32::try_from(v)
+                    .map(|v| (5, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -297,6 +358,14 @@ impl Unpackable for i64 {
             Format::INT16 => take_num(&mut buf, i16::from_be_bytes).map(|v| (3, v as i64)),
             Format::INT32 => take_num(&mut buf, i32::from_be_bytes).map(|v| (5, v as i64)),
             Format::INT64 => take_num(&mut buf, i64::from_be_bytes).map(|v| (9, v)),
+            Format::UINT8 => take_byte(&mut buf).map(|v| (2, v as i64)),
+            Format::UINT16 => take_num(&mut buf, u16::from_be_bytes).map(|v| (3, v as i64)),
+            Format::UINT32 => take_num(&mut buf, u32::from_be_bytes).map(|v| (5, v as i64)),
+            Format::UINT64 => take_num(&mut buf, u64::from_be_bytes).and_then(|v| {
+                i64::try_from(v)
+                    .map(|v| (9, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -314,6 +383,14 @@ impl Unpackable for i64 {
             Format::INT16 => take_num_iter(bytes, i16::from_be_bytes).map(|v| (3, v as i64)),
             Format::INT32 => take_num_iter(bytes, i32::from_be_bytes).map(|v| (5, v as i64)),
             Format::INT64 => take_num_iter(bytes, i64::from_be_bytes).map(|v| (9, v)),
+            Format::UINT8 => take_byte_iter(bytes).map(|v| (2, v as i64)),
+            Format::UINT16 => take_num_iter(bytes, u16::from_be_bytes).map(|v| (3, v as i64)),
+            Format::UINT32 => take_num_iter(bytes, u32::from_be_bytes).map(|v| (5, v as i64)),
+            Format::UINT64 => take_num_iter(bytes, u64::from_be_bytes).and_then(|v| {
+                i64::try_from(v)
+                    .map(|v| (9, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -331,6 +408,10 @@ impl Unpackable for i128 {
             Format::INT16 => take_num(&mut buf, i16::from_be_bytes).map(|v| (3, v as i128)),
             Format::INT32 => take_num(&mut buf, i32::from_be_bytes).map(|v| (5, v as i128)),
             Format::INT64 => take_num(&mut buf, i64::from_be_bytes).map(|v| (9, v as i128)),
+            Format::UINT8 => take_byte(&mut buf).map(|v| (2, v as i128)),
+            Format::UINT16 => take_num(&mut buf, u16::from_be_bytes).map(|v| (3, v as i128)),
+            Format::UINT32 => take_num(&mut buf, u32::from_be_bytes).map(|v| (5, v as i128)),
+            Format::UINT64 => take_num(&mut buf, u64::from_be_bytes).map(|v| (9, v as i128)),
             Format::BIN8 => {
                 if take_byte(&mut buf)? != 16 {
                     return Err(Error::UnexpectedBinLength);
@@ -354,6 +435,10 @@ impl Unpackable for i128 {
             Format::INT16 => take_num_iter(bytes, i16::from_be_bytes).map(|v| (3, v as i128)),
             Format::INT32 => take_num_iter(bytes, i32::from_be_bytes).map(|v| (5, v as i128)),
             Format::INT64 => take_num_iter(bytes, i64::from_be_bytes).map(|v| (9, v as i128)),
+            Format::UINT8 => take_byte_iter(bytes).map(|v| (2, v as i128)),
+            Format::UINT16 => take_num_iter(bytes, u16::from_be_bytes).map(|v| (3, v as i128)),
+            Format::UINT32 => take_num_iter(bytes, u32::from_be_bytes).map(|v| (5, v as i128)),
+            Format::UINT64 => take_num_iter(bytes, u64::from_be_bytes).map(|v| (9, v as i128)),
             Format::BIN8 => {
                 if take_byte_iter(bytes.by_ref())? != 16 {
                     return Err(Error::UnexpectedBinLength);
@@ -377,6 +462,18 @@ impl Unpackable for isize {
             Format::INT16 => take_num(&mut buf, i16::from_be_bytes).map(|v| (3, v as isize)),
             Format::INT32 => take_num(&mut buf, i32::from_be_bytes).map(|v| (5, v as isize)),
             Format::INT64 => take_num(&mut buf, i64::from_be_bytes).map(|v| (9, v as isize)),
+            Format::UINT8 => take_byte(&mut buf).map(|v| (2, v as isize)),
+            Format::UINT16 => take_num(&mut buf, u16::from_be_bytes).map(|v| (3, v as isize)),
+            Format::UINT32 => take_num(&mut buf, u32::from_be_bytes).and_then(|v| {
+                isize::try_from(v)
+                    .map(|v| (5, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
+            Format::UINT64 => take_num(&mut buf, u64::from_be_bytes).and_then(|v| {
+                isize::try_from(v)
+                    .map(|v| (9, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
@@ -394,7 +491,175 @@ impl Unpackable for isize {
             Format::INT16 => take_num_iter(bytes, i16::from_be_bytes).map(|v| (3, v as isize)),
             Format::INT32 => take_num_iter(bytes, i32::from_be_bytes).map(|v| (5, v as isize)),
             Format::INT64 => take_num_iter(bytes, i64::from_be_bytes).map(|v| (9, v as isize)),
+            Format::UINT8 => take_byte_iter(bytes).map(|v| (2, v as isize)),
+            Format::UINT16 => take_num_iter(bytes, u16::from_be_bytes).map(|v| (3, v as isize)),
+            Format::UINT32 => take_num_iter(bytes, u32::from_be_bytes).and_then(|v| {
+                isize::try_from(v)
+                    .map(|v| (5, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
+            Format::UINT64 => take_num_iter(bytes, u64::from_be_bytes).and_then(|v| {
+                isize::try_from(v)
+                    .map(|v| (9, v))
+                    .map_err(|_| Error::UnexpectedFormatTag)
+            }),
             _ => Err(Error::UnexpectedFormatTag),
         }
     }
 }
+
+macro_rules! non_zero {
+    ($t:ty, $n:ty) => {
+        impl Unpackable for $t {
+            type Error = Error;
+
+            fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                let (n, v) = <$n>::unpack(buf)?;
+                <$t>::new(v).map(|v| (n, v)).ok_or(Error::UnexpectedZero)
+            }
+
+            fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+            where
+                I: IntoIterator<Item = u8>,
+            {
+                let (n, v) = <$n>::unpack_iter(bytes)?;
+                <$t>::new(v).map(|v| (n, v)).ok_or(Error::UnexpectedZero)
+            }
+        }
+    };
+}
+
+non_zero!(NonZeroU8, u8);
+non_zero!(NonZeroU16, u16);
+non_zero!(NonZeroU32, u32);
+non_zero!(NonZeroU64, u64);
+non_zero!(NonZeroU128, u128);
+non_zero!(NonZeroUsize, usize);
+non_zero!(NonZeroI8, i8);
+non_zero!(NonZeroI16, i16);
+non_zero!(NonZeroI32, i32);
+non_zero!(NonZeroI64, i64);
+non_zero!(NonZeroI128, i128);
+non_zero!(NonZeroIsize, isize);
+
+macro_rules! wrapper {
+    ($w:ident) => {
+        impl<X> Unpackable for $w<X>
+        where
+            X: Unpackable,
+        {
+            type Error = <X as Unpackable>::Error;
+
+            fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                X::unpack(buf).map(|(n, x)| (n, $w(x)))
+            }
+
+            fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+            where
+                I: IntoIterator<Item = u8>,
+            {
+                X::unpack_iter(bytes).map(|(n, x)| (n, $w(x)))
+            }
+        }
+    };
+}
+
+wrapper!(Wrapping);
+wrapper!(Saturating);
+
+macro_rules! atomic {
+    ($t:ident, $n:ty, $size:literal) => {
+        #[cfg(target_has_atomic = $size)]
+        impl Unpackable for atomic::$t {
+            type Error = Error;
+
+            fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                <$n>::unpack(buf).map(|(n, v)| (n, Self::new(v)))
+            }
+
+            fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+            where
+                I: IntoIterator<Item = u8>,
+            {
+                <$n>::unpack_iter(bytes).map(|(n, v)| (n, Self::new(v)))
+            }
+        }
+    };
+}
+
+atomic!(AtomicU8, u8, "8");
+atomic!(AtomicU16, u16, "16");
+atomic!(AtomicU32, u32, "32");
+atomic!(AtomicU64, u64, "64");
+atomic!(AtomicUsize, usize, "ptr");
+atomic!(AtomicI8, i8, "8");
+atomic!(AtomicI16, i16, "16");
+atomic!(AtomicI32, i32, "32");
+atomic!(AtomicI64, i64, "64");
+atomic!(AtomicIsize, isize, "ptr");
+
+#[cfg(feature = "num-bigint")]
+mod num_bigint {
+    use super::*;
+    use ::num_bigint::{BigInt, BigUint};
+
+    fn is_bin(format: u8) -> bool {
+        matches!(format, Format::BIN8 | Format::BIN16 | Format::BIN32)
+    }
+
+    impl Unpackable for BigInt {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            match buf.first() {
+                Some(f) if is_bin(*f) => {
+                    let (n, bytes) = unpack_bytes(buf)?;
+                    Ok((n, BigInt::from_signed_bytes_be(bytes)))
+                }
+                _ => i128::unpack(buf).map(|(n, x)| (n, x.into())),
+            }
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let mut bytes = bytes.into_iter().peekable();
+            match bytes.peek() {
+                Some(f) if is_bin(*f) => {
+                    let (n, bytes) = unpack_bytes_vec_iter(bytes)?;
+                    Ok((n, BigInt::from_signed_bytes_be(&bytes)))
+                }
+                _ => i128::unpack_iter(bytes).map(|(n, x)| (n, x.into())),
+            }
+        }
+    }
+
+    impl Unpackable for BigUint {
+        type Error = Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            match buf.first() {
+                Some(f) if is_bin(*f) => {
+                    let (n, bytes) = unpack_bytes(buf)?;
+                    Ok((n, BigUint::from_bytes_be(bytes)))
+                }
+                _ => u128::unpack(buf).map(|(n, x)| (n, x.into())),
+            }
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            let mut bytes = bytes.into_iter().peekable();
+            match bytes.peek() {
+                Some(f) if is_bin(*f) => {
+                    let (n, bytes) = unpack_bytes_vec_iter(bytes)?;
+                    Ok((n, BigUint::from_bytes_be(&bytes)))
+                }
+                _ => u128::unpack_iter(bytes).map(|(n, x)| (n, x.into())),
+            }
+        }
+    }
+}
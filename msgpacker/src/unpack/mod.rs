@@ -1,9 +1,28 @@
 use super::{helpers, Error, Format, Unpackable};
 
 mod binary;
+#[cfg(feature = "alloc")]
+mod bulk;
 mod collections;
 mod common;
 mod float;
 mod int;
+mod net;
+#[cfg(feature = "std")]
+mod os;
+mod pointer;
+mod range;
 
 pub use collections::{unpack_array, unpack_array_iter, unpack_map, unpack_map_iter};
+
+#[cfg(feature = "alloc")]
+pub use collections::{unpack_map_unique, unpack_map_unique_iter};
+
+#[cfg(feature = "alloc")]
+pub use bulk::{
+    unpack_f32_vec, unpack_f64_vec, unpack_i16_vec, unpack_i32_vec, unpack_i64_vec, unpack_u16_vec,
+    unpack_u32_vec, unpack_u64_vec,
+};
+
+#[cfg(feature = "bytes")]
+pub use binary::{unpack_bytes_shared, unpack_str_shared};
@@ -0,0 +1,175 @@
+use super::{
+    binary::unpack_bytes,
+    helpers::{take_byte, take_byte_iter, take_num_iter},
+    Error, Format, Unpackable,
+};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// Fixed array header of the address and port pair of a socket address.
+const SOCKET: u8 = 0x92;
+
+/// Takes the octets of an address from the iterator, returning the amount of read bytes, the
+/// octets and their length.
+///
+/// Fails with [Error::UnexpectedBinLength] if the length isn't the one of an IPv4 or IPv6 address.
+fn take_octets_iter<I>(mut bytes: I) -> Result<(usize, [u8; 16], usize), Error>
+where
+    I: Iterator<Item = u8>,
+{
+    let format = take_byte_iter(bytes.by_ref())?;
+    let (n, len) = match format {
+        Format::BIN8 => (2, take_byte_iter(bytes.by_ref())? as usize),
+        Format::BIN16 => (
+            3,
+            take_num_iter(bytes.by_ref(), u16::from_be_bytes)? as usize,
+        ),
+        Format::BIN32 => (
+            5,
+            take_num_iter(bytes.by_ref(), u32::from_be_bytes)? as usize,
+        ),
+        _ => return Err(Error::UnexpectedFormatTag),
+    };
+    if len != 4 && len != 16 {
+        return Err(Error::UnexpectedBinLength);
+    }
+    let mut octets = [0u8; 16];
+    for o in &mut octets[..len] {
+        *o = take_byte_iter(bytes.by_ref())?;
+    }
+    Ok((n + len, octets, len))
+}
+
+fn ip(octets: &[u8]) -> Result<IpAddr, Error> {
+    match octets.len() {
+        4 => Ok(IpAddr::from(<[u8; 4]>::try_from(octets).unwrap())),
+        16 => Ok(IpAddr::from(<[u8; 16]>::try_from(octets).unwrap())),
+        _ => Err(Error::UnexpectedBinLength),
+    }
+}
+
+impl Unpackable for IpAddr {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        let (n, octets) = unpack_bytes(buf)?;
+        Ok((n, ip(octets)?))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let (n, octets, len) = take_octets_iter(bytes.into_iter())?;
+        Ok((n, ip(&octets[..len])?))
+    }
+}
+
+impl Unpackable for Ipv4Addr {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        match IpAddr::unpack(buf)? {
+            (n, IpAddr::V4(ip)) => Ok((n, ip)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        match IpAddr::unpack_iter(bytes)? {
+            (n, IpAddr::V4(ip)) => Ok((n, ip)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+}
+
+impl Unpackable for Ipv6Addr {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        match IpAddr::unpack(buf)? {
+            (n, IpAddr::V6(ip)) => Ok((n, ip)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        match IpAddr::unpack_iter(bytes)? {
+            (n, IpAddr::V6(ip)) => Ok((n, ip)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+}
+
+impl Unpackable for SocketAddr {
+    type Error = Error;
+
+    fn unpack(mut buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        if take_byte(&mut buf)? != SOCKET {
+            return Err(Error::UnexpectedFormatTag);
+        }
+        let (n, ip) = IpAddr::unpack(buf)?;
+        let (m, port) = u16::unpack(&buf[n..])?;
+        Ok((1 + n + m, SocketAddr::new(ip, port)))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter();
+        if take_byte_iter(bytes.by_ref())? != SOCKET {
+            return Err(Error::UnexpectedFormatTag);
+        }
+        let (n, ip) = IpAddr::unpack_iter(bytes.by_ref())?;
+        let (m, port) = u16::unpack_iter(bytes)?;
+        Ok((1 + n + m, SocketAddr::new(ip, port)))
+    }
+}
+
+impl Unpackable for SocketAddrV4 {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        match SocketAddr::unpack(buf)? {
+            (n, SocketAddr::V4(addr)) => Ok((n, addr)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        match SocketAddr::unpack_iter(bytes)? {
+            (n, SocketAddr::V4(addr)) => Ok((n, addr)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+}
+
+impl Unpackable for SocketAddrV6 {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        match SocketAddr::unpack(buf)? {
+            (n, SocketAddr::V6(addr)) => Ok((n, addr)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        match SocketAddr::unpack_iter(bytes)? {
+            (n, SocketAddr::V6(addr)) => Ok((n, addr)),
+            _ => Err(Error::UnexpectedBinLength),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::{Error, Format, Unpackable};
+use std::{
+    ffi::{CString, OsString},
+    path::PathBuf,
+};
+
+#[cfg(unix)]
+use std::os::unix::ffi::OsStringExt;
+
+/// Converts the bytes of a binary into an OS string.
+///
+/// Fails with [Error::InvalidUtf8] if the bytes are not valid UTF-8 on platforms other than unix,
+/// where the bytes of an OS string cannot be recovered.
+fn os_string(bytes: Vec<u8>) -> Result<OsString, Error> {
+    #[cfg(unix)]
+    return Ok(OsString::from_vec(bytes));
+    #[cfg(not(unix))]
+    String::from_utf8(bytes)
+        .map(OsString::from)
+        .map_err(|_| Error::InvalidUtf8)
+}
+
+fn is_bin(format: u8) -> bool {
+    matches!(format, Format::BIN8 | Format::BIN16 | Format::BIN32)
+}
+
+impl Unpackable for OsString {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        match buf.first() {
+            Some(f) if is_bin(*f) => {
+                let (n, bytes) = Vec::<u8>::unpack(buf)?;
+                Ok((n, os_string(bytes)?))
+            }
+            _ => String::unpack(buf).map(|(n, s)| (n, s.into())),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter().peekable();
+        match bytes.peek() {
+            Some(f) if is_bin(*f) => {
+                let (n, bytes) = Vec::<u8>::unpack_iter(bytes)?;
+                Ok((n, os_string(bytes)?))
+            }
+            _ => String::unpack_iter(bytes).map(|(n, s)| (n, s.into())),
+        }
+    }
+}
+
+impl Unpackable for PathBuf {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        OsString::unpack(buf).map(|(n, s)| (n, s.into()))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        OsString::unpack_iter(bytes).map(|(n, s)| (n, s.into()))
+    }
+}
+
+impl Unpackable for CString {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        let (n, bytes) = Vec::<u8>::unpack(buf)?;
+        let s = CString::new(bytes).map_err(|_| Error::InvalidCString)?;
+        Ok((n, s))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let (n, bytes) = Vec::<u8>::unpack_iter(bytes)?;
+        let s = CString::new(bytes).map_err(|_| Error::InvalidCString)?;
+        Ok((n, s))
+    }
+}
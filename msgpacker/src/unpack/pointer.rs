@@ -0,0 +1,196 @@
+use super::Unpackable;
+use core::{
+    cell::{Cell, RefCell},
+    cmp::Reverse,
+};
+
+macro_rules! wrapper {
+    ($w:ident) => {
+        impl<X> Unpackable for $w<X>
+        where
+            X: Unpackable,
+        {
+            type Error = <X as Unpackable>::Error;
+
+            fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                X::unpack(buf).map(|(n, x)| (n, $w::new(x)))
+            }
+
+            fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+            where
+                I: IntoIterator<Item = u8>,
+            {
+                X::unpack_iter(bytes).map(|(n, x)| (n, $w::new(x)))
+            }
+        }
+    };
+}
+
+wrapper!(Cell);
+wrapper!(RefCell);
+
+impl<X> Unpackable for Reverse<X>
+where
+    X: Unpackable,
+{
+    type Error = <X as Unpackable>::Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        X::unpack(buf).map(|(n, x)| (n, Reverse(x)))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        X::unpack_iter(bytes).map(|(n, x)| (n, Reverse(x)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::Error;
+    use ::alloc::{
+        borrow::{Cow, ToOwned},
+        boxed::Box,
+        rc::Rc,
+        string::String,
+        sync::Arc,
+        vec::Vec,
+    };
+
+    /// Maximum nesting of the pointers being unpacked by a thread.
+    #[cfg(feature = "std")]
+    const MAX_DEPTH: usize = 256;
+
+    #[cfg(feature = "std")]
+    std::thread_local! {
+        static DEPTH: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Unpacks the pointee of a pointer, failing with [Error::MaxDepthExceeded] when the nesting of
+    /// pointers exceeds [MAX_DEPTH] so deeply nested input can't overflow the stack.
+    #[cfg(feature = "std")]
+    fn nested<T, E, F>(f: F) -> Result<T, E>
+    where
+        E: From<Error>,
+        F: FnOnce() -> Result<T, E>,
+    {
+        struct Guard;
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                DEPTH.with(|d| d.set(d.get() - 1));
+            }
+        }
+
+        let depth = DEPTH.with(|d| d.replace(d.get() + 1));
+        let _guard = Guard;
+        if depth >= MAX_DEPTH {
+            return Err(Error::MaxDepthExceeded.into());
+        }
+        f()
+    }
+
+    /// Without `std` there is no thread local storage to track the nesting, so the depth isn't
+    /// limited.
+    #[cfg(not(feature = "std"))]
+    fn nested<T, E, F>(f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        f()
+    }
+
+    // Recursive types are boxed, so the iterator is erased here; otherwise every level of
+    // recursion would instantiate the unpacking with a new iterator type.
+    macro_rules! pointer {
+        ($p:ident) => {
+            impl<X> Unpackable for $p<X>
+            where
+                X: Unpackable,
+            {
+                type Error = <X as Unpackable>::Error;
+
+                fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                    nested(|| X::unpack(buf)).map(|(n, x)| (n, $p::new(x)))
+                }
+
+                fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+                where
+                    I: IntoIterator<Item = u8>,
+                {
+                    let mut bytes = bytes.into_iter();
+                    let bytes: &mut dyn Iterator<Item = u8> = &mut bytes;
+                    nested(|| X::unpack_iter(bytes)).map(|(n, x)| (n, $p::new(x)))
+                }
+            }
+        };
+    }
+
+    pointer!(Box);
+    pointer!(Rc);
+    pointer!(Arc);
+
+    macro_rules! unsized_pointer {
+        ($p:ident) => {
+            impl Unpackable for $p<str> {
+                type Error = <String as Unpackable>::Error;
+
+                fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                    String::unpack(buf).map(|(n, s)| (n, s.into()))
+                }
+
+                fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+                where
+                    I: IntoIterator<Item = u8>,
+                {
+                    String::unpack_iter(bytes).map(|(n, s)| (n, s.into()))
+                }
+            }
+
+            impl<X> Unpackable for $p<[X]>
+            where
+                Vec<X>: Unpackable,
+            {
+                type Error = <Vec<X> as Unpackable>::Error;
+
+                fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+                    Vec::<X>::unpack(buf).map(|(n, v)| (n, v.into()))
+                }
+
+                fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+                where
+                    I: IntoIterator<Item = u8>,
+                {
+                    Vec::<X>::unpack_iter(bytes).map(|(n, v)| (n, v.into()))
+                }
+            }
+        };
+    }
+
+    unsized_pointer!(Box);
+    unsized_pointer!(Rc);
+    unsized_pointer!(Arc);
+
+    impl<'a, X> Unpackable for Cow<'a, X>
+    where
+        X: ToOwned + ?Sized,
+        <X as ToOwned>::Owned: Unpackable,
+    {
+        type Error = <<X as ToOwned>::Owned as Unpackable>::Error;
+
+        fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+            X::Owned::unpack(buf).map(|(n, x)| (n, Cow::Owned(x)))
+        }
+
+        fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+        where
+            I: IntoIterator<Item = u8>,
+        {
+            X::Owned::unpack_iter(bytes).map(|(n, x)| (n, Cow::Owned(x)))
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::{
+    helpers::{take_byte, take_byte_iter},
+    Error, Unpackable,
+};
+use core::ops::{Bound, Range, RangeInclusive};
+
+/// Fixed array header of the start and end pair of a range.
+const PAIR: u8 = 0x92;
+
+/// Unpacks the start and end pair of a range from the buffer.
+fn unpack_pair<X>(mut buf: &[u8]) -> Result<(usize, X, X), <X as Unpackable>::Error>
+where
+    X: Unpackable,
+{
+    if take_byte(&mut buf)? != PAIR {
+        return Err(Error::UnexpectedFormatTag.into());
+    }
+    let (n, start) = X::unpack(buf)?;
+    let (m, end) = X::unpack(&buf[n..])?;
+    Ok((1 + n + m, start, end))
+}
+
+/// Unpacks the start and end pair of a range from the iterator.
+fn unpack_pair_iter<I, X>(bytes: I) -> Result<(usize, X, X), <X as Unpackable>::Error>
+where
+    I: IntoIterator<Item = u8>,
+    X: Unpackable,
+{
+    let mut bytes = bytes.into_iter();
+    if take_byte_iter(bytes.by_ref())? != PAIR {
+        return Err(Error::UnexpectedFormatTag.into());
+    }
+    let (n, start) = X::unpack_iter(bytes.by_ref())?;
+    let (m, end) = X::unpack_iter(bytes)?;
+    Ok((1 + n + m, start, end))
+}
+
+impl<X> Unpackable for Range<X>
+where
+    X: Unpackable,
+{
+    type Error = <X as Unpackable>::Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        unpack_pair(buf).map(|(n, start, end)| (n, start..end))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        unpack_pair_iter(bytes).map(|(n, start, end)| (n, start..end))
+    }
+}
+
+impl<X> Unpackable for RangeInclusive<X>
+where
+    X: Unpackable,
+{
+    type Error = <X as Unpackable>::Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        unpack_pair(buf).map(|(n, start, end)| (n, start..=end))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        unpack_pair_iter(bytes).map(|(n, start, end)| (n, start..=end))
+    }
+}
+
+impl<X> Unpackable for Bound<X>
+where
+    X: Unpackable,
+{
+    type Error = <X as Unpackable>::Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        let (n, discriminant) = u32::unpack(buf)?;
+        let buf = &buf[n..];
+        match discriminant {
+            0 => X::unpack(buf).map(|(m, x)| (n + m, Bound::Included(x))),
+            1 => X::unpack(buf).map(|(m, x)| (n + m, Bound::Excluded(x))),
+            2 => Ok((n, Bound::Unbounded)),
+            _ => Err(Error::InvalidEnumVariant.into()),
+        }
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut bytes = bytes.into_iter();
+        let (n, discriminant) = u32::unpack_iter(bytes.by_ref())?;
+        match discriminant {
+            0 => X::unpack_iter(bytes).map(|(m, x)| (n + m, Bound::Included(x))),
+            1 => X::unpack_iter(bytes).map(|(m, x)| (n + m, Bound::Excluded(x))),
+            2 => Ok((n, Bound::Unbounded)),
+            _ => Err(Error::InvalidEnumVariant.into()),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use arrayvec::{ArrayString, ArrayVec};
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+
+mod utils;
+
+#[test]
+fn capacity_overflow() {
+    let mut bytes = vec![];
+    msgpacker::pack_array(&mut bytes, [1u8, 2, 3]);
+    assert_eq!(
+        ArrayVec::<u8, 2>::unpack(&bytes),
+        Err(Error::CapacityOverflow)
+    );
+    assert_eq!(
+        ArrayVec::<u8, 2>::unpack_iter(bytes.clone()),
+        Err(Error::CapacityOverflow)
+    );
+    assert_eq!(
+        ArrayVec::<u8, 3>::unpack(&bytes).map(|(n, v)| (n, v.into_inner())),
+        Ok((4, Ok([1, 2, 3])))
+    );
+
+    let mut bytes = vec![];
+    "foo".pack(&mut bytes);
+    assert_eq!(
+        ArrayString::<2>::unpack(&bytes),
+        Err(Error::CapacityOverflow)
+    );
+    assert_eq!(
+        ArrayString::<2>::unpack_iter(bytes),
+        Err(Error::CapacityOverflow)
+    );
+}
+
+#[test]
+fn invalid_utf8() {
+    let bytes = [0xa2, 0xff, 0xfe];
+    assert_eq!(ArrayString::<4>::unpack(&bytes), Err(Error::InvalidUtf8));
+    assert_eq!(
+        ArrayString::<4>::unpack_iter(bytes),
+        Err(Error::InvalidUtf8)
+    );
+}
+
+proptest! {
+    #[test]
+    fn vec(a: [i32; 4], len in 0..=4usize) {
+        let v = ArrayVec::<i32, 4>::from_iter(a[..len].iter().copied());
+        let mut bytes = vec![];
+        a[..len].to_vec().pack(&mut bytes);
+        let mut other = vec![];
+        v.pack(&mut other);
+        assert_eq!(bytes, other);
+
+        utils::case(v);
+    }
+
+    #[test]
+    fn string(a in "[a-z]{0,16}") {
+        utils::case(ArrayString::<16>::from(&a).unwrap());
+    }
+}
@@ -5,7 +5,7 @@ mod utils;
 
 #[test]
 fn empty_vec() {
-    let v = vec![];
+    let v: Vec<u8> = vec![];
     let mut bytes = vec![];
     let n = v.pack(&mut bytes);
     let (o, x) = Vec::<u8>::unpack(&bytes).unwrap();
@@ -30,6 +30,18 @@ fn empty_str() {
 }
 
 proptest! {
+    #[test]
+    fn slice_buffer(v: Vec<u8>, s: String) {
+        let mut bytes = vec![];
+        let n = v.pack(&mut bytes) + s.pack(&mut bytes);
+
+        let mut slice = vec![0u8; n + 1];
+        let mut cursor = slice.as_mut_slice();
+        assert_eq!(n, v.pack(&mut cursor) + s.pack(&mut cursor));
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(&slice[..n], bytes.as_slice());
+    }
+
     #[test]
     fn vec(v: Vec<u8>) {
         utils::case(v);
@@ -0,0 +1,87 @@
+use msgpacker::prelude::*;
+use msgpacker::Canonical;
+use proptest::prelude::*;
+
+macro_rules! bulk {
+    ($name:ident, $t:ty, $pack:ident, $unpack:ident, $bits:expr) => {
+        proptest! {
+            #[test]
+            fn $name(values: Vec<$t>) {
+                let bits = |v: &[$t]| v.iter().copied().map($bits).collect::<Vec<_>>();
+
+                let mut bytes = Vec::new();
+                let n = msgpacker::$pack(&mut bytes, &values);
+                assert_eq!(n, bytes.len());
+
+                let (o, x) = msgpacker::$unpack(&bytes).unwrap();
+                let (p, y): (usize, Vec<$t>) = msgpacker::unpack_array(&bytes).unwrap();
+                assert_eq!(n, o);
+                assert_eq!(n, p);
+                assert_eq!(bits(&values), bits(&x));
+                assert_eq!(bits(&values), bits(&y));
+
+                let mut bytes = Vec::new();
+                let n = msgpacker::pack_array(&mut bytes, &values);
+                let (o, x) = msgpacker::$unpack(&bytes).unwrap();
+                assert_eq!(n, o);
+                assert_eq!(bits(&values), bits(&x));
+            }
+        }
+    };
+}
+
+bulk!(u16_slice, u16, pack_u16_slice, unpack_u16_vec, |v| v);
+bulk!(u32_slice, u32, pack_u32_slice, unpack_u32_vec, |v| v);
+bulk!(u64_slice, u64, pack_u64_slice, unpack_u64_vec, |v| v);
+bulk!(i16_slice, i16, pack_i16_slice, unpack_i16_vec, |v| v);
+bulk!(i32_slice, i32, pack_i32_slice, unpack_i32_vec, |v| v);
+bulk!(i64_slice, i64, pack_i64_slice, unpack_i64_vec, |v| v);
+bulk!(f32_slice, f32, pack_f32_slice, unpack_f32_vec, f32::to_bits);
+bulk!(f64_slice, f64, pack_f64_slice, unpack_f64_vec, f64::to_bits);
+
+#[test]
+fn bulk_errors() {
+    let mut bytes = Vec::new();
+    msgpacker::pack_u32_slice(&mut bytes, &[1, 2, 3]);
+    bytes.pop();
+    assert_eq!(
+        msgpacker::unpack_u32_vec(&bytes),
+        Err(Error::BufferTooShort)
+    );
+
+    let mut bytes = Vec::new();
+    msgpacker::pack_array(&mut bytes, [true]);
+    assert_eq!(
+        msgpacker::unpack_u32_vec(&bytes),
+        Err(Error::UnexpectedFormatTag)
+    );
+
+    let mut bytes = Vec::new();
+    0u32.pack(&mut bytes);
+    assert_eq!(
+        msgpacker::unpack_u32_vec(&bytes),
+        Err(Error::UnexpectedFormatTag)
+    );
+}
+
+#[test]
+fn not_canonical() {
+    let canonical = Canonical::default();
+
+    let mut bytes = Vec::new();
+    msgpacker::pack_u16_slice(&mut bytes, &[1, 2]);
+    assert_eq!(bytes, [0x92, 0xcd, 0, 1, 0xcd, 0, 2]);
+    assert_eq!(canonical.validate(&bytes), Err(Error::NonCanonical));
+
+    let mut bytes = Vec::new();
+    msgpacker::pack_i64_slice(&mut bytes, &[-1]);
+    assert_eq!(canonical.validate(&bytes), Err(Error::NonCanonical));
+
+    let mut bytes = Vec::new();
+    msgpacker::pack_array(&mut bytes, [1u16, 2]);
+    assert_eq!(canonical.validate(&bytes), Ok(3));
+
+    let mut bytes = Vec::new();
+    msgpacker::pack_f64_slice(&mut bytes, &[0.5, 1.0]);
+    assert_eq!(canonical.validate(&bytes), Ok(bytes.len()));
+}
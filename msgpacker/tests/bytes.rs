@@ -0,0 +1,59 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use msgpacker::{prelude::*, PackBufMut};
+use proptest::prelude::*;
+
+mod utils;
+
+#[test]
+fn shared() {
+    let mut buf = BytesMut::new();
+    b"foo"[..].pack(&mut buf);
+    "bar".pack(&mut buf);
+    let buf = buf.freeze();
+
+    let (n, bin) = msgpacker::unpack_bytes_shared(&buf).unwrap();
+    assert_eq!(n, 5);
+    assert_eq!(bin, &b"foo"[..]);
+    assert_eq!(bin.as_ptr(), buf[2..].as_ptr());
+
+    let rest = buf.slice(n..);
+    let (m, s) = msgpacker::unpack_str_shared(&rest).unwrap();
+    assert_eq!(m, 4);
+    assert_eq!(s, &b"bar"[..]);
+    assert_eq!(s.as_ptr(), buf[n + 1..].as_ptr());
+
+    assert_eq!(
+        msgpacker::unpack_str_shared(&buf),
+        Err(Error::UnexpectedFormatTag)
+    );
+    assert_eq!(
+        msgpacker::unpack_str_shared(&Bytes::from_static(&[0xa2, 0xff, 0xfe])),
+        Err(Error::InvalidUtf8)
+    );
+}
+
+#[test]
+fn buf_mut() {
+    let value = (1u32, "foo", vec![1u8, 2, 3]);
+    let mut bytes = vec![];
+    value.pack(&mut bytes);
+
+    let mut buf = PackBufMut(BytesMut::new().limit(64));
+    let n = value.pack(&mut buf);
+    assert_eq!(n, bytes.len());
+    assert_eq!(buf.0.into_inner(), bytes);
+}
+
+proptest! {
+    #[test]
+    fn bytes(a: Vec<u8>) {
+        let mut bytes = vec![];
+        a.pack(&mut bytes);
+        let mut other = vec![];
+        Bytes::from(a.clone()).pack(&mut other);
+        assert_eq!(bytes, other);
+
+        utils::case(Bytes::from(a.clone()));
+        utils::case(BytesMut::from(a.as_slice()));
+    }
+}
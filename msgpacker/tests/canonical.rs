@@ -0,0 +1,172 @@
+use msgpacker::prelude::*;
+use msgpacker::Canonical;
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+fn validate(bytes: &[u8]) -> Result<usize, Error> {
+    Canonical::default().validate(bytes)
+}
+
+#[test]
+fn non_minimal_int() {
+    assert_eq!(validate(&[0xcc, 5]), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xcd, 0, 5]), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xce, 0, 0, 1, 0]), Err(Error::NonCanonical));
+    assert_eq!(
+        validate(&[0xcf, 0, 0, 0, 0, 0, 1, 0, 0]),
+        Err(Error::NonCanonical)
+    );
+    assert_eq!(validate(&[0xd0, 0xf0]), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xd1, 0, 5]), Err(Error::NonCanonical));
+    assert_eq!(
+        validate(&[0xd2, 0xff, 0xff, 0xff, 0x80]),
+        Err(Error::NonCanonical)
+    );
+    assert_eq!(validate(&[0xd1, 0, 0xc8]), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xd2, 0, 0, 0x01, 0]), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xcc, 0x80]), Ok(2));
+    assert_eq!(validate(&[0xcc, 0xc8]), Ok(2));
+    assert_eq!(validate(&[0xd0, 0x80]), Ok(2));
+}
+
+#[test]
+fn non_minimal_header() {
+    assert_eq!(validate(&[0xd9, 1, b'a']), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xc5, 0, 1, 0]), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xdc, 0, 1, 0]), Err(Error::NonCanonical));
+    assert_eq!(validate(&[0xde, 0, 1, 0, 0]), Err(Error::NonCanonical));
+    assert_eq!(
+        validate(&[0xc7, 4, 1, 0, 0, 0, 0]),
+        Err(Error::NonCanonical)
+    );
+    assert_eq!(validate(&[0xc4, 1, 0]), Ok(3));
+    assert_eq!(validate(&[0xc7, 3, 1, 0, 0, 0]), Ok(6));
+}
+
+#[test]
+fn non_minimal_timestamp() {
+    let mut bytes = vec![0xd7, 0xff];
+    bytes.extend(10u64.to_be_bytes());
+    assert_eq!(validate(&bytes), Err(Error::NonCanonical));
+
+    let mut bytes = vec![0xc7, 12, 0xff];
+    bytes.extend(0u32.to_be_bytes());
+    bytes.extend(10i64.to_be_bytes());
+    assert_eq!(validate(&bytes), Err(Error::NonCanonical));
+
+    let mut bytes = vec![0xc7, 12, 0xff];
+    bytes.extend(0u32.to_be_bytes());
+    bytes.extend((-10i64).to_be_bytes());
+    assert_eq!(validate(&bytes), Ok(15));
+
+    let mut bytes = vec![0xc7, 12, 0xff];
+    bytes.extend(1_000_000_000u32.to_be_bytes());
+    bytes.extend((-10i64).to_be_bytes());
+    assert_eq!(validate(&bytes), Err(Error::InvalidExtension));
+}
+
+#[test]
+fn duplicate_map_key() {
+    assert_eq!(validate(&[0x82, 1, 2, 1, 3]), Err(Error::DuplicateMapKey));
+    assert_eq!(
+        validate(&[0x82, 0xa1, b'a', 2, 0xa1, b'a', 3]),
+        Err(Error::DuplicateMapKey)
+    );
+    assert_eq!(validate(&[0x82, 1, 2, 2, 2]), Ok(5));
+}
+
+#[test]
+fn minimal_floats() {
+    let mut bytes = vec![];
+    1.5f64.pack(&mut bytes);
+    assert_eq!(validate(&bytes), Ok(9));
+
+    let canonical = Canonical {
+        minimal_floats: true,
+        ..Default::default()
+    };
+    assert_eq!(canonical.validate(&bytes), Err(Error::NonCanonical));
+
+    let mut bytes = vec![];
+    0.1f64.pack(&mut bytes);
+    assert_eq!(canonical.validate(&bytes), Ok(9));
+}
+
+#[test]
+fn max_depth() {
+    let canonical = Canonical {
+        max_depth: 2,
+        ..Default::default()
+    };
+    assert_eq!(canonical.validate(&[0x91, 0x91, 0x90]), Ok(3));
+    assert_eq!(
+        canonical.validate(&[0x91, 0x91, 0x91, 0x90]),
+        Err(Error::MaxDepthExceeded)
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, MsgPacker)]
+struct Pair {
+    a: u8,
+    b: u16,
+}
+
+#[test]
+fn unpack_every_value() {
+    let canonical = Canonical::default();
+    assert_eq!(canonical.unpack::<(u8, u16)>(&[1, 5]), Ok((2, (1, 5))));
+    assert_eq!(
+        canonical.unpack::<(u8, u16)>(&[1, 0xcc, 5]),
+        Err(Error::NonCanonical)
+    );
+    assert_eq!(
+        canonical.unpack::<(u8, u16, u32)>(&[1, 2, 0xcd, 0, 5]),
+        Err(Error::NonCanonical)
+    );
+
+    assert_eq!(
+        canonical.unpack::<Pair>(&[1, 5]),
+        Ok((2, Pair { a: 1, b: 5 }))
+    );
+    assert_eq!(
+        canonical.unpack::<Pair>(&[1, 0xcc, 5]),
+        Err(Error::NonCanonical)
+    );
+
+    assert_eq!(
+        canonical.unpack::<Result<u16, u8>>(&[0, 0xcc, 5]),
+        Err(Error::NonCanonical)
+    );
+    assert_eq!(canonical.unpack::<Result<u16, u8>>(&[0, 5]), Ok((2, Ok(5))));
+    assert_eq!(
+        canonical.unpack::<Option<(u8, u16)>>(&[1, 0xcd, 0, 5]),
+        Err(Error::NonCanonical)
+    );
+}
+
+proptest! {
+    #[test]
+    fn packed_is_canonical(
+        a: (u64, i64, u128, i128, String, Vec<u8>, Option<bool>, f32, f64),
+        m: BTreeMap<String, Option<i16>>,
+        t: i8,
+        d: Vec<u8>,
+        secs: u64,
+        nanos: u32,
+    ) {
+        let e = Extension::Ext(t.max(0), d);
+        let ts = Extension::Timestamp(Duration::new(secs >> 30, nanos % 1_000_000_000));
+        let mut bytes = vec![];
+        let n = a.pack(&mut bytes) + m.pack(&mut bytes) + e.pack(&mut bytes) + ts.pack(&mut bytes);
+
+        let mut buf = bytes.as_slice();
+        let mut o = 0;
+        for _ in 0..12 {
+            let c = validate(buf).unwrap();
+            buf = &buf[c..];
+            o += c;
+        }
+        assert_eq!(n, o);
+    }
+}
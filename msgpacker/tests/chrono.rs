@@ -0,0 +1,54 @@
+use chrono::{DateTime, TimeZone, Timelike, Utc};
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+
+mod utils;
+
+#[test]
+fn formats() {
+    let mut bytes = vec![];
+    DateTime::from_timestamp(10, 0).unwrap().pack(&mut bytes);
+    assert_eq!(bytes, [0xd6, 0xff, 0, 0, 0, 10]);
+
+    let mut bytes = vec![];
+    DateTime::from_timestamp(10, 1).unwrap().pack(&mut bytes);
+    assert_eq!(bytes, [0xd7, 0xff, 0, 0, 0, 4, 0, 0, 0, 10]);
+}
+
+#[test]
+fn leap_second() {
+    let t = Utc
+        .with_ymd_and_hms(2016, 12, 31, 23, 59, 59)
+        .unwrap()
+        .with_nanosecond(1_500_000_000)
+        .unwrap();
+    let mut bytes = vec![];
+    t.pack(&mut bytes);
+    let (_, u) = DateTime::<Utc>::unpack(&bytes).unwrap();
+    assert_eq!(
+        u,
+        DateTime::from_timestamp(t.timestamp() + 1, 500_000_000).unwrap()
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let mut bytes = vec![0xc7, 12, 0xff];
+    bytes.extend(0u32.to_be_bytes());
+    bytes.extend(i64::MAX.to_be_bytes());
+    assert_eq!(
+        DateTime::<Utc>::unpack(&bytes),
+        Err(Error::InvalidExtension)
+    );
+    assert_eq!(
+        DateTime::<Utc>::unpack_iter(bytes),
+        Err(Error::InvalidExtension)
+    );
+}
+
+proptest! {
+    #[test]
+    fn date_time(secs in -(1i64 << 40)..(1i64 << 40), nanos in 0..1_000_000_000u32) {
+        utils::case(DateTime::from_timestamp(secs, nanos).unwrap());
+    }
+}
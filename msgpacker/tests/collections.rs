@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 use msgpacker::prelude::*;
 use proptest::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 mod utils;
 
@@ -35,12 +35,152 @@ struct Value {
     pub t14: Option<String>,
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq, MsgPacker, proptest_derive::Arbitrary)]
+struct Sorted {
+    #[msgpacker(sorted = "map")]
+    pub map: HashMap<String, u64>,
+    #[msgpacker(sorted = "set")]
+    pub set: Ids,
+    #[msgpacker(array, sorted)]
+    pub tags: HashSet<String>,
+}
+
+type Ids = HashSet<i32>;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, MsgPacker, proptest_derive::Arbitrary)]
+struct SortedTuple(
+    #[msgpacker(sorted = "map")] HashMap<u8, String>,
+    #[msgpacker(sorted = "set")] HashSet<u16>,
+    #[msgpacker(unique)] HashMap<u8, u8>,
+);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, MsgPacker, proptest_derive::Arbitrary)]
+struct Unique {
+    #[msgpacker(unique)]
+    pub map: HashMap<u8, u8>,
+    #[msgpacker(map, sorted, unique)]
+    pub tree: BTreeMap<String, u8>,
+}
+
+type Words = std::vec::Vec<String>;
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker, proptest_derive::Arbitrary)]
+enum Nested {
+    Empty,
+    Lines(
+        #[proptest(
+            strategy = "prop::collection::vec(prop::collection::vec(any::<String>(), 0..4), 0..4)"
+        )]
+        Vec<Words>,
+    ),
+    Table {
+        #[proptest(
+            strategy = "prop::collection::vec(prop::collection::vec(any::<u32>(), 0..4), 0..4)"
+        )]
+        rows: Vec<Vec<u32>>,
+        bytes: Vec<u8>,
+    },
+}
+
+/// Buffer that records the reservations.
+#[derive(Default)]
+struct Reserving {
+    bytes: Vec<u8>,
+    reserved: Vec<usize>,
+}
+
+impl PackBuf for Reserving {
+    fn put_u8(&mut self, b: u8) {
+        self.bytes.push(b);
+    }
+
+    fn put_slice(&mut self, s: &[u8]) {
+        self.bytes.extend_from_slice(s);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserved.push(additional);
+    }
+}
+
+#[test]
+fn reserve() {
+    let mut buf = Reserving::default();
+    let n = msgpacker::pack_array(&mut buf, [1u32, 2, 300]);
+    assert_eq!(n, 6);
+    assert_eq!(buf.reserved, [4]);
+
+    let mut buf = Reserving::default();
+    msgpacker::try_pack_array(&mut buf, vec![0u16; 20]).unwrap();
+    assert_eq!(buf.reserved, [23]);
+
+    let mut buf = Reserving::default();
+    msgpacker::pack_map(&mut buf, [(1u8, 2u8), (3, 4)]);
+    assert_eq!(buf.reserved, [5]);
+
+    let mut buf = Reserving::default();
+    msgpacker::try_pack_map(&mut buf, &BTreeMap::from([(1u8, 2u8)])).unwrap();
+    assert_eq!(buf.reserved, [3]);
+}
+
+#[test]
+fn vec_bytes() {
+    let bytes = packed(&vec![1u8, 2, 3]);
+    assert_eq!(bytes, [0xc4, 3, 1, 2, 3]);
+    assert_eq!(packed(&[1u8, 2, 3][..]), bytes);
+
+    let bytes = packed(&vec![1u16, 2, 3]);
+    assert_eq!(bytes, [0x93, 1, 2, 3]);
+    assert_eq!(packed(&[1u16, 2, 3][..]), bytes);
+
+    let bytes = packed(&(&b"foo"[..], "bar"));
+    assert_eq!(bytes, packed(&(b"foo".to_vec(), "bar".to_string())));
+}
+
+#[test]
+fn duplicate_keys() {
+    let bytes = [0x82, 1, 2, 1, 3];
+
+    let (n, map): (usize, HashMap<u8, u8>) = msgpacker::unpack_map(&bytes).unwrap();
+    assert_eq!(n, bytes.len());
+    assert_eq!(map, HashMap::from([(1, 3)]));
+
+    assert_eq!(
+        msgpacker::unpack_map_unique::<u8, u8, HashMap<u8, u8>>(&bytes),
+        Err(Error::DuplicateMapKey)
+    );
+    assert_eq!(
+        msgpacker::unpack_map_unique_iter::<_, u8, u8, BTreeMap<u8, u8>>(bytes),
+        Err(Error::DuplicateMapKey)
+    );
+
+    // collections that keep duplicated keys are rejected as well
+    assert_eq!(
+        msgpacker::unpack_map_unique::<u8, u8, Vec<(u8, u8)>>(&bytes),
+        Err(Error::DuplicateMapKey)
+    );
+    assert_eq!(
+        msgpacker::unpack_map_unique_iter::<_, u8, u8, Vec<(u8, u8)>>(bytes),
+        Err(Error::DuplicateMapKey)
+    );
+    assert_eq!(
+        msgpacker::unpack_map_unique::<u8, u8, Vec<(u8, u8)>>(&[0x82, 1, 2, 3, 4]),
+        Ok((5, vec![(1, 2), (3, 4)]))
+    );
+
+    let mut packed = bytes.to_vec();
+    packed.push(0x80);
+    assert_eq!(Unique::unpack(&packed), Err(Error::DuplicateMapKey));
+    assert_eq!(Unique::unpack_iter(packed), Err(Error::DuplicateMapKey));
+}
+
 proptest! {
     #[test]
     fn array(value: Vec<Value>) {
         let mut bytes = Vec::new();
         let n = msgpacker::pack_array(&mut bytes, &value);
         assert_eq!(n, bytes.len());
+        assert_eq!(n, msgpacker::packed_array_len(&value));
         let (o, x): (usize, Vec<Value>) = msgpacker::unpack_array(&bytes).unwrap();
         let (p, y): (usize, Vec<Value>) = msgpacker::unpack_array_iter(bytes).unwrap();
         assert_eq!(n, o);
@@ -49,11 +189,27 @@ proptest! {
         assert_eq!(value, y);
     }
 
+    #[test]
+    fn vec(
+        value in prop::collection::vec(prop::collection::vec(any::<String>(), 0..8), 0..8),
+        bytes in prop::collection::vec(any::<Vec<u8>>(), 0..8),
+    ) {
+        assert_eq!(packed(&value), packed(value.as_slice()));
+        utils::case(value.clone());
+        utils::case((bytes, value, 1u8));
+    }
+
+    #[test]
+    fn vec_derive(value in prop::collection::vec(any::<Nested>(), 0..8)) {
+        utils::case(value);
+    }
+
     #[test]
     fn map(map: HashMap<Value, Value>) {
         let mut bytes = Vec::new();
         let n = msgpacker::pack_map(&mut bytes, &map);
         assert_eq!(n, bytes.len());
+        assert_eq!(n, msgpacker::packed_map_len(&map));
         let (o, x): (usize, HashMap<Value, Value>) = msgpacker::unpack_map(&bytes).unwrap();
         let (p, y): (usize, HashMap<Value, Value>) = msgpacker::unpack_map_iter(bytes).unwrap();
         assert_eq!(n, o);
@@ -61,4 +217,130 @@ proptest! {
         assert_eq!(map, x);
         assert_eq!(map, y);
     }
+
+    #[test]
+    fn unique_map(map: HashMap<Value, Value>) {
+        let mut bytes = Vec::new();
+        let n = msgpacker::pack_map(&mut bytes, &map);
+        let (o, x): (usize, HashMap<Value, Value>) = msgpacker::unpack_map_unique(&bytes).unwrap();
+        let (p, y): (usize, HashMap<Value, Value>) = msgpacker::unpack_map_unique_iter(bytes).unwrap();
+        assert_eq!(n, o);
+        assert_eq!(n, p);
+        assert_eq!(map, x);
+        assert_eq!(map, y);
+    }
+
+    #[test]
+    fn unique_derive(value: Unique) {
+        utils::case(value);
+    }
+
+    #[test]
+    fn sorted_array(value: HashSet<Value>) {
+        let mut bytes = Vec::new();
+        let n = msgpacker::pack_array_sorted(&mut bytes, &value);
+        assert_eq!(n, bytes.len());
+        assert_eq!(n, msgpacker::packed_array_len(&value));
+
+        let mut other = Vec::new();
+        let copy: HashSet<Value> = value.iter().cloned().collect();
+        assert_eq!(Ok(n), msgpacker::try_pack_array_sorted(&mut other, &copy));
+        assert_eq!(bytes, other);
+
+        let (o, x): (usize, Vec<Value>) = msgpacker::unpack_array(&bytes).unwrap();
+        assert_eq!(n, o);
+        assert!(x.windows(2).all(|w| packed(&w[0]) < packed(&w[1])));
+        assert_eq!(value, x.into_iter().collect());
+    }
+
+    #[test]
+    fn sorted_map(map: HashMap<Value, Value>) {
+        let mut bytes = Vec::new();
+        let n = msgpacker::pack_map_sorted(&mut bytes, &map);
+        assert_eq!(n, bytes.len());
+        assert_eq!(n, msgpacker::packed_map_len(&map));
+
+        let mut other = Vec::new();
+        let copy: HashMap<Value, Value> = map.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        assert_eq!(Ok(n), msgpacker::try_pack_map_sorted(&mut other, &copy));
+        assert_eq!(bytes, other);
+
+        let (o, x): (usize, Vec<(Value, Value)>) = msgpacker::unpack_map(&bytes).unwrap();
+        assert_eq!(n, o);
+        assert!(x.windows(2).all(|w| packed(&w[0].0) < packed(&w[1].0)));
+        assert_eq!(map, x.into_iter().collect());
+    }
+
+    #[test]
+    fn sorted_derive(value: Sorted) {
+        utils::case(value.clone());
+
+        let copy = Sorted {
+            map: value.map.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            set: value.set.iter().copied().collect(),
+            tags: value.tags.iter().cloned().collect(),
+        };
+        let mut bytes = Vec::new();
+        let mut other = Vec::new();
+        value.pack(&mut bytes);
+        copy.pack(&mut other);
+        assert_eq!(bytes, other);
+    }
+
+    #[test]
+    fn sorted_derive_tuple(value: SortedTuple) {
+        utils::case(value.clone());
+
+        let copy = SortedTuple(
+            value.0.iter().map(|(k, v)| (*k, v.clone())).collect(),
+            value.1.iter().copied().collect(),
+            value.2.clone(),
+        );
+        let mut bytes = Vec::new();
+        value.pack(&mut bytes);
+        let mut expected = Vec::new();
+        msgpacker::pack_map_sorted(&mut expected, &value.0);
+        msgpacker::pack_array_sorted(&mut expected, &value.1);
+        assert_eq!(bytes[..expected.len()], expected);
+
+        let mut other = Vec::new();
+        copy.pack(&mut other);
+        assert_eq!(bytes[..expected.len()], other[..expected.len()]);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker)]
+struct SortedNested {
+    #[msgpacker(sorted = "map")]
+    map: HashMap<u8, HashSet<u16>>,
+}
+
+#[test]
+fn sorted_top_level_only() {
+    // nested collections are packed by their own implementation, in their iteration order
+    let value = SortedNested {
+        map: HashMap::from([
+            (2, HashSet::from([300, 1, 20, 4_000])),
+            (1, HashSet::from([5, 60_000, 7])),
+        ]),
+    };
+    let mut bytes = Vec::new();
+    value.pack(&mut bytes);
+
+    let mut expected = vec![0x82, 1];
+    value.map[&1].pack(&mut expected);
+    expected.push(2);
+    value.map[&2].pack(&mut expected);
+    assert_eq!(bytes, expected);
+
+    // nested sequences keep their order
+    let mut bytes = Vec::new();
+    msgpacker::pack_map_sorted(&mut bytes, HashMap::from([(1u8, vec![3u8, 1, 2])]));
+    assert_eq!(bytes, [0x81, 1, 0xc4, 3, 3, 1, 2]);
+}
+
+fn packed<X: Packable + ?Sized>(x: &X) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    x.pack(&mut bytes);
+    bytes
 }
@@ -1,5 +1,12 @@
-use msgpacker::prelude::*;
+use msgpacker::{prelude::*, TaggedOption};
 use proptest::prelude::*;
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    cmp::Reverse,
+    rc::Rc,
+    sync::Arc,
+};
 
 mod utils;
 
@@ -14,6 +21,101 @@ fn bool() {
     utils::case(false);
 }
 
+#[test]
+fn ambiguous_option() {
+    let mut bytes = vec![];
+    Some(None::<u8>).pack(&mut bytes);
+    assert_eq!(Option::<Option<u8>>::unpack(&bytes), Ok((1, None)));
+
+    utils::case(TaggedOption(Some(None::<u8>)));
+    utils::case(TaggedOption(None::<Option<u8>>));
+    utils::case(TaggedOption(Some(())));
+    utils::case(TaggedOption(None::<()>));
+
+    let mut bytes = vec![];
+    TaggedOption(Some(())).pack(&mut bytes);
+    assert_eq!(bytes, [0x91]);
+    assert_eq!(
+        TaggedOption::<u8>::unpack(&[0x92, 1, 2]),
+        Err(Error::UnexpectedFormatTag)
+    );
+}
+
+#[test]
+fn result() {
+    utils::case(Ok::<(), ()>(()));
+    utils::case(Err::<(), ()>(()));
+
+    let mut bytes = vec![];
+    Foo::Bar.pack(&mut bytes);
+    assert_eq!(Result::<(), ()>::unpack(&bytes), Ok((1, Ok(()))));
+
+    let mut bytes = vec![];
+    2u32.pack(&mut bytes);
+    assert_eq!(
+        Result::<u8, u8>::unpack(&bytes),
+        Err(Error::InvalidEnumVariant)
+    );
+    assert_eq!(
+        Result::<u8, u8>::unpack_iter(bytes),
+        Err(Error::InvalidEnumVariant)
+    );
+}
+
+#[test]
+fn duration() {
+    let mut bytes = vec![];
+    std::time::Duration::new(3, 5).pack(&mut bytes);
+    assert_eq!(bytes, [0x92, 3, 5]);
+
+    let mut bytes = vec![0x92, 3];
+    1_000_000_000u32.pack(&mut bytes);
+    assert_eq!(
+        std::time::Duration::unpack(&bytes),
+        Err(Error::InvalidDuration)
+    );
+    assert_eq!(
+        std::time::Duration::unpack_iter(bytes),
+        Err(Error::InvalidDuration)
+    );
+}
+
+#[test]
+fn ordering() {
+    utils::case(std::cmp::Ordering::Less);
+    utils::case(std::cmp::Ordering::Equal);
+    utils::case(std::cmp::Ordering::Greater);
+
+    let mut bytes = vec![];
+    2i8.pack(&mut bytes);
+    assert_eq!(
+        std::cmp::Ordering::unpack(&bytes),
+        Err(Error::InvalidEnumVariant)
+    );
+}
+
+#[test]
+fn invalid_char() {
+    for x in [0xd800u32, 0xdfff, 0x110000] {
+        let mut bytes = vec![];
+        x.pack(&mut bytes);
+        assert_eq!(char::unpack(&bytes), Err(Error::InvalidChar));
+        assert_eq!(char::unpack_iter(bytes), Err(Error::InvalidChar));
+    }
+}
+
+#[test]
+fn atomic_bool() {
+    use std::sync::atomic::AtomicBool;
+
+    let mut bytes = vec![];
+    AtomicBool::new(true).pack(&mut bytes);
+    assert_eq!(bytes, [0xc3]);
+    let (n, x) = AtomicBool::unpack(&bytes).unwrap();
+    assert_eq!(n, 1);
+    assert!(x.into_inner());
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, MsgPacker, proptest_derive::Arbitrary)]
 pub enum Foo {
     Bar,
@@ -21,7 +123,255 @@ pub enum Foo {
     Qux { a: Vec<u8>, b: u64 },
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker)]
+pub struct Node {
+    value: u32,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+#[test]
+fn recursive_box() {
+    let leaf = |value| Node {
+        value,
+        left: None,
+        right: None,
+    };
+    utils::case(Node {
+        value: 1,
+        left: Some(Box::new(Node {
+            value: 2,
+            left: Some(Box::new(leaf(3))),
+            right: None,
+        })),
+        right: Some(Box::new(leaf(4))),
+    });
+}
+
+#[test]
+fn recursive_box_depth() {
+    // Each node is its value, followed by its left child and a nil right child.
+    let nested = |depth| {
+        let mut bytes = vec![0x00; depth];
+        bytes.extend(vec![0xc0; depth + 1]);
+        bytes
+    };
+
+    let bytes = nested(200);
+    let (n, _) = Node::unpack(&bytes).unwrap();
+    assert_eq!(n, bytes.len());
+
+    let bytes = nested(100_000);
+    assert_eq!(Node::unpack(&bytes), Err(Error::MaxDepthExceeded));
+    assert_eq!(Node::unpack_iter(bytes), Err(Error::MaxDepthExceeded));
+
+    // The depth is restored after a failure.
+    let bytes = nested(200);
+    assert!(Node::unpack_iter(bytes).is_ok());
+}
+
+#[test]
+fn unsized_pointers() {
+    utils::case(Box::<str>::from("foo"));
+    utils::case(Rc::<str>::from("foo"));
+    utils::case(Arc::<str>::from("foo"));
+    utils::case(Box::<[u8]>::from(&[1u8, 2, 3][..]));
+    utils::case(Rc::<[u8]>::from(&[1u8, 2, 3][..]));
+    utils::case(Arc::<[u8]>::from(&[1u8, 2, 3][..]));
+    utils::case(Cow::<str>::Owned("foo".into()));
+    utils::case(Cow::<[u8]>::Owned(vec![1, 2, 3]));
+
+    let mut bytes = vec![];
+    Cow::Borrowed("foo").pack(&mut bytes);
+    assert_eq!(bytes, [0xa3, b'f', b'o', b'o']);
+}
+
+thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Tracked(String);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPS.with(|d| d.set(d.get() + 1));
+    }
+}
+
+impl Unpackable for Tracked {
+    type Error = Error;
+
+    fn unpack(buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        String::unpack(buf).map(|(n, s)| (n, Tracked(s)))
+    }
+
+    fn unpack_iter<I>(bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        String::unpack_iter(bytes).map(|(n, s)| (n, Tracked(s)))
+    }
+}
+
+#[test]
+fn array_partial_drop() {
+    let mut bytes = vec![];
+    "foo".pack(&mut bytes);
+    "bar".pack(&mut bytes);
+    true.pack(&mut bytes);
+    "baz".pack(&mut bytes);
+
+    DROPS.with(|d| d.set(0));
+    assert!(<[Tracked; 4]>::unpack(&bytes).is_err());
+    assert_eq!(DROPS.with(|d| d.get()), 2);
+
+    DROPS.with(|d| d.set(0));
+    assert!(<[Tracked; 4]>::unpack_iter(bytes).is_err());
+    assert_eq!(DROPS.with(|d| d.get()), 2);
+}
+
+#[test]
+fn array_complete_drop() {
+    let mut bytes = vec![];
+    "foo".pack(&mut bytes);
+    "bar".pack(&mut bytes);
+    "baz".pack(&mut bytes);
+
+    DROPS.with(|d| d.set(0));
+    let (_, array) = <[Tracked; 3]>::unpack(&bytes).unwrap();
+    assert_eq!(DROPS.with(|d| d.get()), 0);
+    drop(array);
+    assert_eq!(DROPS.with(|d| d.get()), 3);
+}
+
+/// A value that is too large to be represented by the protocol.
+#[derive(Debug)]
+struct Oversized;
+
+impl Packable for Oversized {
+    fn pack<T>(&self, _buf: &mut T) -> usize
+    where
+        T: PackBuf,
+    {
+        0
+    }
+
+    fn try_pack<T>(&self, _buf: &mut T) -> Result<usize, PackError>
+    where
+        T: PackBuf,
+    {
+        Err(PackError::LengthOverflow)
+    }
+}
+
+impl Unpackable for Oversized {
+    type Error = Error;
+
+    fn unpack(_buf: &[u8]) -> Result<(usize, Self), Self::Error> {
+        Err(Error::BufferTooShort)
+    }
+
+    fn unpack_iter<I>(_bytes: I) -> Result<(usize, Self), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        Err(Error::BufferTooShort)
+    }
+}
+
+#[derive(MsgPacker)]
+struct WithOversized {
+    a: u32,
+    b: Option<Oversized>,
+    #[msgpacker(array)]
+    c: Vec<Oversized>,
+}
+
+#[derive(MsgPacker)]
+enum OversizedVariant {
+    A(u32, Oversized),
+}
+
+#[test]
+fn try_pack_overflow() {
+    let mut bytes = vec![];
+    assert_eq!(
+        (1u8, Oversized).try_pack(&mut bytes),
+        Err(PackError::LengthOverflow)
+    );
+    assert_eq!(
+        Some([Oversized]).try_pack(&mut bytes),
+        Err(PackError::LengthOverflow)
+    );
+    assert_eq!(
+        msgpacker::pack_into_slice(&(1u8, Oversized), &mut [0u8; 8]),
+        Err(PackError::LengthOverflow)
+    );
+    assert_eq!(
+        msgpacker::try_pack_array(&mut bytes, [Oversized].iter()),
+        Err(PackError::LengthOverflow)
+    );
+    assert_eq!(
+        msgpacker::try_pack_map(&mut bytes, [(1u8, Oversized)].iter()),
+        Err(PackError::LengthOverflow)
+    );
+
+    let value = WithOversized {
+        a: 1,
+        b: None,
+        c: vec![],
+    };
+    let n = value.try_pack(&mut bytes).unwrap();
+    assert_eq!(n, value.packed_len());
+
+    let value = WithOversized {
+        a: 1,
+        b: None,
+        c: vec![Oversized],
+    };
+    assert_eq!(value.try_pack(&mut bytes), Err(PackError::LengthOverflow));
+
+    let value = WithOversized {
+        a: 1,
+        b: Some(Oversized),
+        c: vec![],
+    };
+    assert_eq!(value.try_pack(&mut bytes), Err(PackError::LengthOverflow));
+
+    let value = OversizedVariant::A(1, Oversized);
+    assert_eq!(value.try_pack(&mut bytes), Err(PackError::LengthOverflow));
+}
+
 proptest! {
+    #[test]
+    fn pack_into_slice(a: (i32, String, bool, usize)) {
+        let mut bytes = vec![];
+        let n = a.pack(&mut bytes);
+
+        let mut buf = vec![0u8; n];
+        assert_eq!(msgpacker::pack_into_slice(&a, &mut buf), Ok(n));
+        assert_eq!(buf, bytes);
+
+        let mut buf = vec![0u8; n - 1];
+        assert_eq!(
+            msgpacker::pack_into_slice(&a, &mut buf),
+            Err(PackError::BufferTooShort)
+        );
+        let m = n - a.3.packed_len();
+        assert_eq!(buf[..m], bytes[..m], "the elements that fit are written");
+    }
+
+    #[test]
+    fn pack_extend(a: (i32, String, Vec<u8>, bool)) {
+        let mut bytes = vec![];
+        let n = a.pack(&mut bytes);
+
+        let mut buf = msgpacker::PackExtend(std::collections::VecDeque::new());
+        assert_eq!(a.pack(&mut buf), n);
+        assert_eq!(buf.0, bytes);
+    }
+
     #[test]
     fn array(a: [i32; 4]) {
         utils::case(a);
@@ -36,4 +386,39 @@ proptest! {
     fn enum_foo(a: Foo) {
         utils::case(a);
     }
+
+    #[test]
+    fn result_values(a: Result<u32, String>, b: Result<Option<u8>, Vec<u8>>) {
+        utils::case(a);
+        utils::case(b);
+    }
+
+    #[test]
+    fn tagged_option(a: Option<Option<u8>>, b: Option<Option<Option<()>>>) {
+        utils::case(TaggedOption(a));
+        utils::case(TaggedOption(b.map(|b| TaggedOption(b.map(TaggedOption)))));
+    }
+
+    #[test]
+    fn duration_values(a: std::time::Duration) {
+        utils::case(a);
+    }
+
+    #[test]
+    fn char(a: char) {
+        utils::case(a);
+    }
+
+    #[test]
+    fn pointers(a: u64, b: String, c: Vec<u8>) {
+        utils::case(Box::new(a));
+        utils::case(Rc::new(b.clone()));
+        utils::case(Arc::new(c.clone()));
+        utils::case(Cell::new(a));
+        utils::case(RefCell::new(b.clone()));
+        utils::case(Reverse(c.clone()));
+        utils::case(Box::<str>::from(b.as_str()));
+        utils::case(Rc::<[u8]>::from(c.as_slice()));
+        utils::case(Cow::<str>::Owned(b));
+    }
 }
@@ -0,0 +1,129 @@
+use msgpacker::prelude::*;
+use msgpacker::{Ext, ExtRegistry};
+use proptest::prelude::*;
+
+mod utils;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, proptest_derive::Arbitrary)]
+struct Uuid([u8; 16]);
+
+impl MsgPackExt for Uuid {
+    const TYPE: i8 = 5;
+
+    fn payload_len(&self) -> usize {
+        16
+    }
+
+    fn encode<T>(&self, buf: &mut T)
+    where
+        T: PackBuf,
+    {
+        buf.put_slice(&self.0);
+    }
+
+    fn decode(payload: &[u8]) -> Result<Self, Error> {
+        payload
+            .try_into()
+            .map(Uuid)
+            .map_err(|_| Error::InvalidExtension)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker, proptest_derive::Arbitrary)]
+#[msgpacker(ext = 6)]
+struct Decimal {
+    mantissa: i64,
+    scale: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker, proptest_derive::Arbitrary)]
+#[msgpacker(ext = 7)]
+enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker)]
+#[msgpacker(ext = 8)]
+struct Marker;
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker, proptest_derive::Arbitrary)]
+struct Account {
+    #[proptest(strategy = "any::<Uuid>().prop_map(Ext)")]
+    id: Ext<Uuid>,
+    balance: Decimal,
+    shape: Option<Shape>,
+}
+
+#[test]
+fn derived_payload() {
+    let decimal = Decimal {
+        mantissa: 5,
+        scale: 2,
+    };
+    let mut bytes = vec![];
+    decimal.pack(&mut bytes);
+    let (_, ext) = Extension::unpack(&bytes).unwrap();
+    assert_eq!(ext, Extension::Ext(6, vec![5, 2]));
+    assert_eq!(ext.decode::<Decimal>(), Ok(decimal.clone()));
+    assert_eq!(Extension::encode(&decimal), ext);
+
+    let ext = Extension::Ext(6, vec![5, 2, 1]);
+    assert_eq!(ext.decode::<Decimal>(), Err(Error::InvalidExtension));
+    assert_eq!(ext.decode::<Uuid>(), Err(Error::InvalidExtension));
+
+    utils::case(Marker);
+}
+
+#[test]
+fn registry() {
+    let mut registry = ExtRegistry::new();
+    registry.register::<Uuid>().register::<Decimal>();
+    assert!(registry.contains(Uuid::TYPE));
+    assert!(!registry.contains(Shape::TYPE));
+
+    let uuid = Uuid([7; 16]);
+    let ext = Extension::encode(&uuid);
+    let x = registry.decode(&ext).unwrap().unwrap();
+    assert_eq!(x.downcast_ref::<Uuid>(), Some(&uuid));
+
+    let ext = Extension::encode(&Shape::Circle(3));
+    assert!(registry.decode(&ext).is_none());
+
+    let ext = Extension::Ext(Uuid::TYPE, vec![1, 2, 3]);
+    assert!(matches!(
+        registry.decode(&ext),
+        Some(Err(Error::InvalidExtension))
+    ));
+}
+
+#[test]
+fn wrong_type() {
+    let mut bytes = vec![];
+    Ext(Uuid([1; 16])).pack(&mut bytes);
+    assert_eq!(Decimal::unpack(&bytes), Err(Error::InvalidExtension));
+    assert_eq!(Ext::<Uuid>::unpack(&bytes).map(|(n, _)| n), Ok(bytes.len()));
+}
+
+proptest! {
+    #[test]
+    fn ext_uuid(x: Uuid) {
+        utils::case(Ext(x));
+    }
+
+    #[test]
+    fn ext_decimal(x: Decimal) {
+        utils::case(x);
+    }
+
+    #[test]
+    fn ext_shape(x: Shape) {
+        utils::case(x);
+    }
+
+    #[test]
+    fn ext_account(x: Account) {
+        utils::case(x);
+    }
+}
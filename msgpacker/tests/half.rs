@@ -0,0 +1,39 @@
+use half::{bf16, f16};
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+
+mod utils;
+
+#[derive(Debug, Clone, PartialEq, MsgPacker)]
+pub struct Weights {
+    pub a: f16,
+    pub b: Vec<bf16>,
+}
+
+#[test]
+fn widened() {
+    let mut bytes = vec![];
+    f16::from_f32(1.5).pack(&mut bytes);
+    let mut other = vec![];
+    1.5f32.pack(&mut other);
+    assert_eq!(bytes, other);
+
+    assert_eq!(f16::unpack(&other), Ok((5, f16::from_f32(1.5))));
+    assert_eq!(bf16::unpack_iter(other), Ok((5, bf16::from_f32(1.5))));
+
+    let mut bytes = vec![];
+    1.5f64.pack(&mut bytes);
+    assert_eq!(f16::unpack(&bytes), Err(Error::UnexpectedFormatTag));
+}
+
+proptest! {
+    #[test]
+    fn half(a: u16, b: Vec<u16>) {
+        let a = f16::from_bits(a);
+        let b: Vec<_> = b.into_iter().map(bf16::from_bits).collect();
+        prop_assume!(!a.is_nan() && !b.iter().any(|b| b.is_nan()));
+
+        utils::case(a);
+        utils::case(Weights { a, b });
+    }
+}
@@ -0,0 +1,24 @@
+use hashbrown::{HashMap, HashSet};
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use std::hash::RandomState;
+
+mod utils;
+
+proptest! {
+    #[test]
+    fn map(a: std::collections::HashMap<u64, String>) {
+        let mut bytes = vec![];
+        a.pack(&mut bytes);
+        let (_, x) = HashMap::<u64, String, RandomState>::unpack(&bytes).unwrap();
+        assert_eq!(x.len(), a.len());
+        assert!(x.iter().all(|(k, v)| a.get(k) == Some(v)));
+
+        utils::case(HashMap::<_, _, RandomState>::from_iter(a));
+    }
+
+    #[test]
+    fn set(a: Vec<i32>) {
+        utils::case(HashSet::<_, RandomState>::from_iter(a));
+    }
+}
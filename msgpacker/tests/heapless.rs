@@ -0,0 +1,82 @@
+use heapless::{FnvIndexMap, FnvIndexSet, LinearMap, String, Vec};
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+
+mod utils;
+
+#[test]
+fn capacity_overflow() {
+    let mut bytes = vec![];
+    msgpacker::pack_array(&mut bytes, [1u8, 2, 3]);
+    assert_eq!(Vec::<u8, 2>::unpack(&bytes), Err(Error::CapacityOverflow));
+    assert_eq!(
+        Vec::<u8, 2>::unpack_iter(bytes.clone()),
+        Err(Error::CapacityOverflow)
+    );
+    assert_eq!(
+        FnvIndexSet::<u8, 2>::unpack(&bytes),
+        Err(Error::CapacityOverflow)
+    );
+
+    let mut bytes = vec![];
+    "foo".pack(&mut bytes);
+    assert_eq!(String::<2>::unpack(&bytes), Err(Error::CapacityOverflow));
+    assert_eq!(
+        String::<2>::unpack_iter(bytes),
+        Err(Error::CapacityOverflow)
+    );
+
+    let mut bytes = vec![];
+    std::collections::BTreeMap::from([(1u8, 1u8), (2, 2), (3, 3)]).pack(&mut bytes);
+    assert_eq!(
+        LinearMap::<u8, u8, 2>::unpack(&bytes),
+        Err(Error::CapacityOverflow)
+    );
+    assert_eq!(
+        FnvIndexMap::<u8, u8, 2>::unpack_iter(bytes),
+        Err(Error::CapacityOverflow)
+    );
+}
+
+proptest! {
+    #[test]
+    fn vec(a: [i32; 4], len in 0..=4usize) {
+        let v = Vec::<i32, 4>::from_slice(&a[..len]).unwrap();
+        let mut bytes = vec![];
+        a[..len].to_vec().pack(&mut bytes);
+        let mut other = vec![];
+        v.pack(&mut other);
+        assert_eq!(bytes, other);
+
+        utils::case(v);
+    }
+
+    #[test]
+    fn string(a in "[a-z]{0,16}") {
+        utils::case(String::<16>::try_from(a.as_str()).unwrap());
+    }
+
+    #[test]
+    fn maps(a in prop::collection::btree_map(any::<u16>(), any::<u64>(), 0..8)) {
+        let mut bytes = vec![];
+        a.pack(&mut bytes);
+
+        let (_, x) = LinearMap::<u16, u64, 8>::unpack(&bytes).unwrap();
+        assert!(x.iter().eq(a.iter()));
+        utils::case(x);
+
+        let (_, x) = FnvIndexMap::<u16, u64, 8>::unpack(&bytes).unwrap();
+        assert!(x.iter().eq(a.iter()));
+        utils::case(x);
+    }
+
+    #[test]
+    fn set(a in prop::collection::btree_set(any::<u16>(), 0..8)) {
+        let mut bytes = vec![];
+        a.pack(&mut bytes);
+
+        let (_, x) = FnvIndexSet::<u16, 8>::unpack(&bytes).unwrap();
+        assert!(x.iter().eq(a.iter()));
+        utils::case(x);
+    }
+}
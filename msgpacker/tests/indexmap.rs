@@ -0,0 +1,33 @@
+use indexmap::{IndexMap, IndexSet};
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use std::hash::RandomState;
+
+mod utils;
+
+#[test]
+fn insertion_order() {
+    let map = IndexMap::<_, _, RandomState>::from_iter([(3u8, 'c'), (1, 'a'), (2, 'b')]);
+    let mut bytes = vec![];
+    map.pack(&mut bytes);
+    assert_eq!(
+        bytes,
+        [0x83, 3, 0x63, 1, 0x61, 2, 0x62],
+        "entries are packed in insertion order"
+    );
+
+    let (_, x) = IndexMap::<u8, char, RandomState>::unpack(&bytes).unwrap();
+    assert!(x.keys().eq([3, 1, 2].iter()));
+}
+
+proptest! {
+    #[test]
+    fn map(a: Vec<(u64, String)>) {
+        utils::case(IndexMap::<_, _, RandomState>::from_iter(a));
+    }
+
+    #[test]
+    fn set(a: Vec<i32>) {
+        utils::case(IndexSet::<_, RandomState>::from_iter(a));
+    }
+}
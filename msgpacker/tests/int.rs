@@ -1,7 +1,56 @@
+use msgpacker::prelude::*;
 use proptest::prelude::*;
+use std::{
+    num::{NonZeroI128, NonZeroI8, NonZeroU16, NonZeroU64, NonZeroUsize, Saturating, Wrapping},
+    sync::atomic::{AtomicI64, AtomicU8, Ordering},
+};
 
 mod utils;
 
+#[test]
+fn non_zero_zero() {
+    let mut bytes = vec![];
+    0u64.pack(&mut bytes);
+    assert_eq!(NonZeroU64::unpack(&bytes), Err(Error::UnexpectedZero));
+    assert_eq!(NonZeroI8::unpack_iter(bytes), Err(Error::UnexpectedZero));
+}
+
+#[test]
+fn atomic() {
+    let x = AtomicI64::new(-300);
+    let mut bytes = vec![];
+    let n = x.pack(&mut bytes);
+    assert_eq!(n, x.packed_len());
+    assert_eq!(bytes, packed(-300i64));
+    let (o, y) = AtomicI64::unpack(&bytes).unwrap();
+    assert_eq!(n, o);
+    assert_eq!(y.load(Ordering::Relaxed), -300);
+
+    let (_, y) = AtomicU8::unpack_iter(packed(200u8)).unwrap();
+    assert_eq!(y.into_inner(), 200);
+}
+
+#[test]
+fn signed_non_negative() {
+    assert_eq!(packed(200i16), [0xcc, 0xc8]);
+    assert_eq!(packed(40_000i32), packed(40_000u32));
+    assert_eq!(packed(i64::MAX), packed(i64::MAX as u64));
+    assert_eq!(packed(u64::MAX as i128), packed(u64::MAX));
+    assert_eq!(i16::unpack(&[0xd1, 0, 0xc8]), Ok((3, 200)));
+    assert_eq!(i16::unpack(&[0xcc, 0xc8]), Ok((2, 200)));
+    assert_eq!(i8::unpack(&[0xcc, 0xc8]), Err(Error::UnexpectedFormatTag));
+    assert_eq!(
+        i32::unpack_iter([0xce, 0x80, 0, 0, 0]),
+        Err(Error::UnexpectedFormatTag)
+    );
+}
+
+fn packed<X: Packable>(x: X) -> Vec<u8> {
+    let mut bytes = vec![];
+    x.pack(&mut bytes);
+    bytes
+}
+
 proptest! {
     #[test]
     fn u8(x: u8) {
@@ -62,4 +111,20 @@ proptest! {
     fn isize(x: isize) {
         utils::case(x);
     }
+
+    #[test]
+    fn non_zero(a: NonZeroU16, b: NonZeroUsize, c: NonZeroI8, d: NonZeroI128) {
+        assert_eq!(packed(a), packed(a.get()));
+        utils::case(a);
+        utils::case(b);
+        utils::case(c);
+        utils::case(d);
+    }
+
+    #[test]
+    fn wrapping(a: u32, b: i16) {
+        assert_eq!(packed(Wrapping(a)), packed(a));
+        utils::case(Wrapping(a));
+        utils::case(Saturating(b));
+    }
 }
@@ -0,0 +1,54 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+mod utils;
+
+#[test]
+fn formats() {
+    let mut bytes = vec![];
+    Ipv4Addr::new(10, 0, 0, 1).pack(&mut bytes);
+    assert_eq!(bytes, [0xc4, 4, 10, 0, 0, 1]);
+
+    let mut bytes = vec![];
+    SocketAddr::from(([127, 0, 0, 1], 8080)).pack(&mut bytes);
+    assert_eq!(bytes, [0x92, 0xc4, 4, 127, 0, 0, 1, 0xcd, 0x1f, 0x90]);
+}
+
+#[test]
+fn invalid_length() {
+    let mut bytes = vec![];
+    [1u8, 2, 3][..].pack(&mut bytes);
+    assert_eq!(IpAddr::unpack(&bytes), Err(Error::UnexpectedBinLength));
+    assert_eq!(IpAddr::unpack_iter(bytes), Err(Error::UnexpectedBinLength));
+
+    let mut bytes = vec![];
+    Ipv6Addr::LOCALHOST.pack(&mut bytes);
+    assert_eq!(Ipv4Addr::unpack(&bytes), Err(Error::UnexpectedBinLength));
+    assert_eq!(
+        Ipv4Addr::unpack_iter(bytes),
+        Err(Error::UnexpectedBinLength)
+    );
+
+    let mut bytes = vec![];
+    Ipv4Addr::LOCALHOST.pack(&mut bytes);
+    assert_eq!(SocketAddr::unpack(&bytes), Err(Error::UnexpectedFormatTag));
+}
+
+proptest! {
+    #[test]
+    fn ip(a: Ipv4Addr, b: Ipv6Addr, c: IpAddr) {
+        utils::case(a);
+        utils::case(b);
+        utils::case(c);
+    }
+
+    #[test]
+    fn socket(a: SocketAddrV4, b: Ipv6Addr, port: u16) {
+        let b = SocketAddrV6::new(b, port, 0, 0);
+        utils::case(a);
+        utils::case(b);
+        utils::case(SocketAddr::V4(a));
+        utils::case(SocketAddr::V6(b));
+    }
+}
@@ -0,0 +1,52 @@
+use msgpacker::prelude::*;
+use num_bigint::{BigInt, BigUint};
+use proptest::prelude::*;
+
+mod utils;
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker)]
+pub struct Balance {
+    pub signed: BigInt,
+    pub unsigned: BigUint,
+}
+
+#[test]
+fn beyond_128_bits() {
+    let x = BigInt::from(i128::MIN) - 1u8;
+    let mut bytes = vec![];
+    x.pack(&mut bytes);
+    assert_eq!(bytes[..2], [0xc4, 17]);
+    assert_eq!(bytes[2..], x.to_signed_bytes_be());
+
+    let x = BigUint::from(u128::MAX) + 1u8;
+    let mut bytes = vec![];
+    x.pack(&mut bytes);
+    assert_eq!(bytes[..2], [0xc4, 17]);
+    assert_eq!(bytes[2..], x.to_bytes_be());
+}
+
+proptest! {
+    #[test]
+    fn int(a: i128, b: u128) {
+        let mut bytes = vec![];
+        a.pack(&mut bytes);
+        let mut other = vec![];
+        BigInt::from(a).pack(&mut other);
+        assert_eq!(bytes, other, "packed as an i128 if the value fits");
+
+        let mut bytes = vec![];
+        b.pack(&mut bytes);
+        let mut other = vec![];
+        BigUint::from(b).pack(&mut other);
+        assert_eq!(bytes, other, "packed as an u128 if the value fits");
+    }
+
+    #[test]
+    fn bigint(a in prop::collection::vec(any::<u8>(), 0..40), b in prop::collection::vec(any::<u8>(), 0..40)) {
+        let signed = BigInt::from_signed_bytes_be(&a);
+        let unsigned = BigUint::from_bytes_be(&b);
+        utils::case(signed.clone());
+        utils::case(unsigned.clone());
+        utils::case(Balance { signed, unsigned });
+    }
+}
@@ -0,0 +1,62 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use std::{
+    ffi::{CString, OsString},
+    path::{Path, PathBuf},
+};
+
+mod utils;
+
+#[test]
+fn utf8_path() {
+    let mut bytes = vec![];
+    Path::new("/tmp/foo").pack(&mut bytes);
+    let mut expected = vec![];
+    "/tmp/foo".pack(&mut expected);
+    assert_eq!(bytes, expected);
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_path() {
+    use std::os::unix::ffi::OsStringExt;
+
+    let path = PathBuf::from(OsString::from_vec(vec![b'/', 0xff, 0xfe]));
+    let mut bytes = vec![];
+    path.pack(&mut bytes);
+    assert_eq!(bytes, [0xc4, 3, b'/', 0xff, 0xfe]);
+    utils::case(path);
+}
+
+#[test]
+fn c_string() {
+    let mut bytes = vec![];
+    c"foo".pack(&mut bytes);
+    assert_eq!(bytes, [0xc4, 3, b'f', b'o', b'o']);
+
+    let mut bytes = vec![];
+    b"f\0o"[..].pack(&mut bytes);
+    assert_eq!(CString::unpack(&bytes), Err(Error::InvalidCString));
+    assert_eq!(CString::unpack_iter(bytes), Err(Error::InvalidCString));
+}
+
+proptest! {
+    #[test]
+    fn path(a: String) {
+        utils::case(PathBuf::from(&a));
+        utils::case(OsString::from(a));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_string_bytes(a: Vec<u8>) {
+        use std::os::unix::ffi::OsStringExt;
+
+        utils::case(OsString::from_vec(a));
+    }
+
+    #[test]
+    fn c_string_values(a in prop::collection::vec(1u8.., 0..64)) {
+        utils::case(CString::new(a).unwrap());
+    }
+}
@@ -0,0 +1,45 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use std::ops::Bound;
+
+mod utils;
+
+fn bound() -> impl Strategy<Value = Bound<i64>> {
+    prop_oneof![
+        any::<i64>().prop_map(Bound::Included),
+        any::<i64>().prop_map(Bound::Excluded),
+        Just(Bound::Unbounded),
+    ]
+}
+
+#[test]
+fn formats() {
+    let mut bytes = vec![];
+    (1u8..5).pack(&mut bytes);
+    assert_eq!(bytes, [0x92, 1, 5]);
+
+    let mut bytes = vec![];
+    Bound::<u8>::Unbounded.pack(&mut bytes);
+    assert_eq!(bytes, [2]);
+
+    assert_eq!(Bound::<u8>::unpack(&[3]), Err(Error::InvalidEnumVariant));
+    assert_eq!(
+        std::ops::Range::<u8>::unpack(&[0x93, 1, 5, 7]),
+        Err(Error::UnexpectedFormatTag)
+    );
+}
+
+proptest! {
+    #[test]
+    fn range(a: u64, b: u64, c: String, d: String) {
+        utils::case(a..b);
+        utils::case(a..=b);
+        utils::case(c..d);
+    }
+
+    #[test]
+    fn bounds(a in bound(), b in bound()) {
+        utils::case(a);
+        utils::case((a, b));
+    }
+}
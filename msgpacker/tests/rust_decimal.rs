@@ -0,0 +1,51 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use rust_decimal::Decimal;
+
+mod utils;
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker)]
+pub struct Price {
+    pub amount: Decimal,
+    pub fee: Option<Decimal>,
+}
+
+#[test]
+fn decimal_str() {
+    let mut bytes = vec![];
+    Decimal::new(-12345, 3).pack(&mut bytes);
+    let mut other = vec![];
+    "-12.345".pack(&mut other);
+    assert_eq!(bytes, other);
+
+    let (_, d) = <Decimal as Unpackable>::unpack(&bytes).unwrap();
+    assert_eq!(d.scale(), 3);
+
+    let mut bytes = vec![];
+    "foo".pack(&mut bytes);
+    assert_eq!(
+        <Decimal as Unpackable>::unpack(&bytes),
+        Err(Error::InvalidDecimal)
+    );
+    assert_eq!(
+        <Decimal as Unpackable>::unpack_iter(bytes),
+        Err(Error::InvalidDecimal)
+    );
+}
+
+proptest! {
+    #[test]
+    fn decimal(lo: u32, mid: u32, hi: u32, negative: bool, scale in 0..=28u32) {
+        let amount = Decimal::from_parts(lo, mid, hi, negative, scale);
+        let mut bytes = vec![];
+        amount.pack(&mut bytes);
+        let (_, d) = <Decimal as Unpackable>::unpack(&bytes).unwrap();
+        assert_eq!(d.scale(), scale);
+
+        utils::case(amount);
+        utils::case(Price {
+            amount,
+            fee: Some(amount),
+        });
+    }
+}
@@ -0,0 +1,41 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use smallvec::SmallVec;
+
+mod utils;
+
+#[test]
+fn inline() {
+    let mut bytes = vec![];
+    vec![1u32, 2, 300].pack(&mut bytes);
+    let (_, x) = SmallVec::<[u32; 4]>::unpack(&bytes).unwrap();
+    assert!(!x.spilled());
+    let (_, y) = SmallVec::<[u32; 4]>::unpack_iter(bytes).unwrap();
+    assert!(!y.spilled());
+    assert_eq!(x.as_slice(), [1, 2, 300]);
+    assert_eq!(x, y);
+}
+
+proptest! {
+    #[test]
+    fn bytes(a: Vec<u8>) {
+        let mut bytes = vec![];
+        a.pack(&mut bytes);
+        let mut other = vec![];
+        SmallVec::<[u8; 8]>::from_vec(a.clone()).pack(&mut other);
+        assert_eq!(bytes, other, "packed as a binary, as a vector of bytes");
+
+        utils::case(SmallVec::<[u8; 8]>::from_vec(a));
+    }
+
+    #[test]
+    fn values(a: Vec<String>) {
+        let mut bytes = vec![];
+        a.pack(&mut bytes);
+        let mut other = vec![];
+        SmallVec::<[String; 2]>::from_vec(a.clone()).pack(&mut other);
+        assert_eq!(bytes, other);
+
+        utils::case(SmallVec::<[String; 2]>::from_vec(a));
+    }
+}
@@ -0,0 +1,43 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use time::OffsetDateTime;
+
+mod utils;
+
+#[test]
+fn formats() {
+    let mut bytes = vec![];
+    OffsetDateTime::from_unix_timestamp(10)
+        .unwrap()
+        .pack(&mut bytes);
+    assert_eq!(bytes, [0xd6, 0xff, 0, 0, 0, 10]);
+
+    let t = OffsetDateTime::from_unix_timestamp(-2).unwrap() + time::Duration::nanoseconds(750);
+    let mut bytes = vec![];
+    t.pack(&mut bytes);
+    let mut expected = vec![0xc7, 12, 0xff];
+    expected.extend(750u32.to_be_bytes());
+    expected.extend((-2i64).to_be_bytes());
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn out_of_range() {
+    let mut bytes = vec![0xc7, 12, 0xff];
+    bytes.extend(0u32.to_be_bytes());
+    bytes.extend(i64::MAX.to_be_bytes());
+    assert_eq!(OffsetDateTime::unpack(&bytes), Err(Error::InvalidExtension));
+    assert_eq!(
+        OffsetDateTime::unpack_iter(bytes),
+        Err(Error::InvalidExtension)
+    );
+}
+
+proptest! {
+    #[test]
+    fn offset_date_time(secs in -(1i64 << 36)..(1i64 << 36), nanos in 0..1_000_000_000u32) {
+        let t = OffsetDateTime::from_unix_timestamp(secs).unwrap()
+            + time::Duration::nanoseconds(nanos as i64);
+        utils::case(t);
+    }
+}
@@ -0,0 +1,90 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod utils;
+
+fn unpack(bytes: &[u8]) -> Result<(usize, SystemTime), Error> {
+    let slice = SystemTime::unpack(bytes);
+    let iter = SystemTime::unpack_iter(bytes.iter().copied());
+    assert_eq!(slice, iter);
+    slice
+}
+
+#[test]
+fn pre_epoch() {
+    let t = UNIX_EPOCH - Duration::new(1, 250_000_000);
+    let mut bytes = vec![];
+    t.pack(&mut bytes);
+
+    let mut expected = vec![0xc7, 12, 0xff];
+    expected.extend(750_000_000u32.to_be_bytes());
+    expected.extend((-2i64).to_be_bytes());
+    assert_eq!(bytes, expected);
+    assert_eq!(unpack(&bytes), Ok((15, t)));
+
+    let ext = Extension::Ext(Extension::TIMESTAMP, expected[3..].to_vec());
+    assert_eq!(Extension::unpack(&bytes), Ok((15, ext.clone())));
+    assert_eq!(Extension::unpack_iter(bytes.clone()), Ok((15, ext.clone())));
+    let mut packed = vec![];
+    ext.pack(&mut packed);
+    assert_eq!(packed, bytes);
+
+    utils::case(UNIX_EPOCH - Duration::from_secs(86400));
+}
+
+#[test]
+fn formats() {
+    let mut bytes = vec![];
+    (UNIX_EPOCH + Duration::from_secs(10)).pack(&mut bytes);
+    assert_eq!(bytes, [0xd6, 0xff, 0, 0, 0, 10]);
+
+    let mut bytes = vec![];
+    (UNIX_EPOCH + Duration::new(10, 1)).pack(&mut bytes);
+    assert_eq!(bytes, [0xd7, 0xff, 0, 0, 0, 4, 0, 0, 0, 10]);
+
+    let d = Duration::new(1 << 40, 5);
+    let mut bytes = vec![];
+    (UNIX_EPOCH + d).pack(&mut bytes);
+    let mut ext = vec![];
+    Extension::Timestamp(d).pack(&mut ext);
+    assert_eq!(bytes, ext);
+    assert_eq!(bytes.len(), 15);
+}
+
+#[test]
+fn invalid() {
+    let nanos = 1_000_000_000u64 << 34;
+    let mut bytes = vec![0xd7, 0xff];
+    bytes.extend(nanos.to_be_bytes());
+    assert_eq!(unpack(&bytes), Err(Error::InvalidExtension));
+
+    let mut bytes = vec![0xc7, 12, 0xff];
+    bytes.extend(1_000_000_000u32.to_be_bytes());
+    bytes.extend((-1i64).to_be_bytes());
+    assert_eq!(unpack(&bytes), Err(Error::InvalidExtension));
+
+    assert_eq!(unpack(&[0xd4, 0xff, 0]), Err(Error::InvalidExtension));
+    assert_eq!(unpack(&[0xd5, 0xff, 0, 0]), Err(Error::InvalidExtension));
+    assert_eq!(
+        unpack(&[0xc7, 5, 0xff, 0, 0, 0, 0, 0]),
+        Err(Error::InvalidExtension)
+    );
+    assert_eq!(
+        unpack(&[0xd6, 0x01, 0, 0, 0, 0]),
+        Err(Error::InvalidExtension)
+    );
+    assert_eq!(unpack(&[0xd6, 0xff, 0, 0]), Err(Error::BufferTooShort));
+}
+
+proptest! {
+    #[test]
+    fn system_time(secs in -(1i64 << 40)..(1i64 << 40), nanos in 0..1_000_000_000u32) {
+        let t = if secs >= 0 {
+            UNIX_EPOCH + Duration::new(secs as u64, nanos)
+        } else {
+            UNIX_EPOCH - Duration::new(secs.unsigned_abs(), 0) + Duration::new(0, nanos)
+        };
+        utils::case(t);
+    }
+}
@@ -9,6 +9,10 @@ where
     let mut bytes = vec![];
     let n = x.pack(&mut bytes);
     assert_eq!(n, bytes.len());
+    assert_eq!(n, x.packed_len());
+    let mut try_bytes = vec![];
+    assert_eq!(Ok(n), x.try_pack(&mut try_bytes));
+    assert_eq!(bytes, try_bytes);
     let (o, y) = T::unpack(&bytes).unwrap();
     let (p, z) = T::unpack_iter(bytes).unwrap();
     assert_eq!(n, o);
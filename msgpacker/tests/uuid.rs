@@ -0,0 +1,36 @@
+use msgpacker::prelude::*;
+use proptest::prelude::*;
+use uuid::Uuid;
+
+mod utils;
+
+#[derive(Debug, Clone, PartialEq, Eq, MsgPacker)]
+pub struct Account {
+    pub id: Uuid,
+    pub parent: Option<Uuid>,
+}
+
+#[test]
+fn uuid_bin() {
+    let uuid = Uuid::from_u128(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+    let mut bytes = vec![];
+    uuid.pack(&mut bytes);
+    assert_eq!(bytes[..2], [0xc4, 16]);
+    assert_eq!(&bytes[2..], uuid.as_bytes());
+
+    let mut bytes = vec![];
+    b"foo"[..].pack(&mut bytes);
+    assert_eq!(Uuid::unpack(&bytes), Err(Error::UnexpectedBinLength));
+    assert_eq!(Uuid::unpack_iter(bytes), Err(Error::UnexpectedBinLength));
+}
+
+proptest! {
+    #[test]
+    fn uuid(a: u128, b: Option<u128>) {
+        utils::case(Uuid::from_u128(a));
+        utils::case(Account {
+            id: Uuid::from_u128(a),
+            parent: b.map(Uuid::from_u128),
+        });
+    }
+}